@@ -0,0 +1,141 @@
+use crate::SubscriptionHandle;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+type Listener<E> = (Rc<Cell<bool>>, Rc<dyn Fn(&E)>);
+
+/// Channel dispatching transient events, such as "scroll to item" or "show toast", to its listeners.
+/// As opposed to a store, an event bus holds no state: events are handled once and never compared.
+pub struct EventBus<E> {
+    listeners: RefCell<Vec<Listener<E>>>,
+}
+
+impl<E> EventBus<E> {
+    /// Create a new event bus without listeners.
+    pub fn new() -> Self {
+        Self {
+            listeners: RefCell::new(vec![]),
+        }
+    }
+
+    /// Invoke every listener with the given `event`.
+    /// Events emitted while no listener is registered are dropped, they are not queued.
+    /// ```rust
+    /// use yewv::EventBus;
+    ///
+    /// let bus = EventBus::new();
+    /// let _handle = bus.listen(|event: &String| println!("{}", event));
+    /// bus.emit("Saved".to_string());
+    /// ```
+    pub fn emit(&self, event: E) {
+        let listeners = {
+            let mut listeners = self.listeners.borrow_mut();
+            listeners.retain(|(active, _)| active.get());
+            listeners.clone()
+        };
+        for (active, listener) in listeners {
+            if active.get() {
+                listener(&event);
+            }
+        }
+    }
+
+    /// Invoke the `callback` with every event emitted from now on.
+    /// The `callback` is invoked for as long as the returned handle is kept alive.
+    pub fn listen(&self, callback: impl Fn(&E) + 'static) -> SubscriptionHandle {
+        let active = Rc::new(Cell::new(true));
+        self.listeners
+            .borrow_mut()
+            .push((active.clone(), Rc::new(callback)));
+        SubscriptionHandle { active }
+    }
+}
+
+impl<E> Default for EventBus<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestContext {
+        bus: EventBus<i32>,
+        handled_events: Rc<RefCell<Vec<i32>>>,
+    }
+
+    fn setup() -> TestContext {
+        TestContext {
+            bus: EventBus::new(),
+            handled_events: Rc::new(RefCell::new(vec![])),
+        }
+    }
+
+    fn listen(ctx: &TestContext) -> SubscriptionHandle {
+        let handled_events = ctx.handled_events.clone();
+        ctx.bus
+            .listen(move |event| handled_events.borrow_mut().push(*event))
+    }
+
+    #[test]
+    fn emit_with_listener_should_handle_event() {
+        //Given
+        let ctx = setup();
+        let _handle = listen(&ctx);
+        //When
+        ctx.bus.emit(1);
+        //Then
+        assert_eq!(*ctx.handled_events.borrow(), &[1]);
+    }
+
+    #[test]
+    fn emit_with_handle_dropped_should_not_handle_event() {
+        //Given
+        let ctx = setup();
+        drop(listen(&ctx));
+        //When
+        ctx.bus.emit(1);
+        //Then
+        assert!(ctx.handled_events.borrow().is_empty());
+        assert!(ctx.bus.listeners.borrow().is_empty());
+    }
+
+    #[test]
+    fn emit_without_listener_should_drop_event() {
+        //Given
+        let ctx = setup();
+        ctx.bus.emit(1);
+        //When
+        let _handle = listen(&ctx);
+        ctx.bus.emit(2);
+        //Then
+        assert_eq!(*ctx.handled_events.borrow(), &[2]);
+    }
+
+    #[test]
+    fn emit_with_event_emitted_by_listener_should_handle_both_events() {
+        //Given
+        let ctx = setup();
+        let bus = Rc::new(EventBus::new());
+        let _emitter = bus.listen({
+            let bus = Rc::downgrade(&bus);
+            move |event: &i32| {
+                if *event == 1 {
+                    bus.upgrade().unwrap().emit(2);
+                }
+            }
+        });
+        let _handle = bus.listen({
+            let handled_events = ctx.handled_events.clone();
+            move |event| handled_events.borrow_mut().push(*event)
+        });
+        //When
+        bus.emit(1);
+        //Then
+        assert_eq!(*ctx.handled_events.borrow(), &[2, 1]);
+    }
+}
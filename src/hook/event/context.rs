@@ -0,0 +1,59 @@
+use super::EventBus;
+use std::{
+    any::type_name,
+    fmt::{self, Debug},
+    ops::Deref,
+    rc::Rc,
+};
+
+/// Context holding a reference to the event bus of events `E`.
+pub struct EventBusContext<E> {
+    pub(crate) bus: Rc<EventBus<E>>,
+}
+
+impl<E> EventBusContext<E> {
+    /// Creates a new `EventBusContext` without listeners.
+    pub fn new() -> Self {
+        Self {
+            bus: Rc::new(EventBus::new()),
+        }
+    }
+}
+
+impl<E> Default for EventBusContext<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> PartialEq for EventBusContext<E> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.bus, &other.bus)
+    }
+}
+
+impl<E> Clone for EventBusContext<E> {
+    fn clone(&self) -> Self {
+        Self {
+            bus: self.bus.clone(),
+        }
+    }
+}
+
+/// Print the event type name along with the address of the event bus, which identifies the context.
+impl<E> Debug for EventBusContext<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventBusContext")
+            .field("event", &type_name::<E>())
+            .field("address", &Rc::as_ptr(&self.bus))
+            .finish()
+    }
+}
+
+impl<E> Deref for EventBusContext<E> {
+    type Target = Rc<EventBus<E>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.bus
+    }
+}
@@ -0,0 +1,67 @@
+mod bus;
+mod context;
+
+use super::message::not_registered;
+pub use bus::*;
+pub use context::*;
+use std::{any::type_name, cell::RefCell, rc::Rc};
+use yew::{hook, use_context, use_state};
+
+/// Handle the events emitted on the event bus of events `E`, for as long as the component is mounted.
+/// Handling an event doesn't re-render the component.
+/// ```rust
+/// use yew::prelude::*;
+/// use yewv::*;
+///
+/// enum Command {
+///     Focus,
+/// }
+///
+/// #[function_component]
+/// fn Search() -> Html {
+///     let input = use_node_ref();
+///     use_store_event::<Command, _>({
+///         let input = input.clone();
+///         move |command| match command {
+///             Command::Focus => { /* Focus the input. */ }
+///         }
+///     });
+///     
+///     html!{ <input ref={input} /> }
+/// }
+///
+/// #[function_component]
+/// fn SearchButton() -> Html {
+///     let bus = use_context::<EventBusContext<Command>>().unwrap();
+///     let onclick = move |_| bus.emit(Command::Focus);
+///     
+///     html!{ <button {onclick}>{ "Search" }</button> }
+/// }
+/// ```
+#[hook]
+pub fn use_store_event<E, F>(handler: F)
+where
+    E: 'static,
+    F: Fn(&E) + 'static,
+{
+    let context = use_context::<EventBusContext<E>>().unwrap_or_else(|| {
+        panic!(
+            "{}",
+            not_registered("use_store_event", "EventBusContext", type_name::<E>())
+        )
+    });
+    let listener = use_state(move || {
+        let handler = Rc::new(RefCell::new(None::<Box<dyn Fn(&E)>>));
+        let handle = context.listen({
+            let handler = handler.clone();
+            move |event| {
+                if let Some(handler) = handler.borrow().as_ref() {
+                    handler(event);
+                }
+            }
+        });
+        (handler, handle)
+    });
+    // The handler is replaced on every render, so it always sees the latest props.
+    *listener.0.borrow_mut() = Some(Box::new(handler));
+}
@@ -0,0 +1,46 @@
+/// Message of the panic raised when `hook` finds no `context` registered for the type `params` in its parents.
+pub(crate) fn not_registered(hook: &str, context: &str, params: &str) -> String {
+    format!(
+        "`{hook}` found no `{context}<{params}>` registered. Wrap a parent component in `ContextProvider<{context}<{params}>>`."
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{any::type_name, panic};
+
+    struct AppState;
+
+    #[test]
+    fn not_registered_with_store_should_name_hook_and_state_type() {
+        //Given
+        let params = type_name::<AppState>();
+        //When
+        let message = not_registered("use_store", "StoreContext", params);
+        //Then
+        assert_eq!(
+            message,
+            "`use_store` found no `StoreContext<yewv::hook::message::tests::AppState>` registered. \
+             Wrap a parent component in `ContextProvider<StoreContext<yewv::hook::message::tests::AppState>>`."
+        );
+    }
+
+    #[test]
+    fn not_registered_when_raised_should_give_message_as_panic_payload() {
+        //Given
+        let params = type_name::<AppState>();
+        //When
+        let payload = panic::catch_unwind(|| {
+            panic!(
+                "{}",
+                not_registered("use_service", "ServiceContext", params)
+            )
+        })
+        .unwrap_err();
+        //Then
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("`use_service` found no `ServiceContext<"));
+        assert!(message.contains("AppState"));
+    }
+}
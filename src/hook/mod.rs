@@ -1,5 +1,8 @@
+mod event;
+mod message;
 mod service;
 mod store;
 
+pub use event::*;
 pub use service::*;
 pub use store::*;
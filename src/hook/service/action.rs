@@ -0,0 +1,40 @@
+use std::future::Future;
+
+/// Run the given asynchronous action to completion in the background.
+/// Typically used by services to fetch data, then update a store once the data is received.
+/// ```rust,no_run
+/// use yewv::*;
+///
+/// #[derive(Clone)]
+/// struct AppState {
+///     loading: bool,
+///     user: Option<String>,
+/// }
+///
+/// struct UserService {
+///     store: StoreContext<AppState>,
+/// }
+///
+/// impl UserService {
+///     fn load_user(&self) {
+///         self.store.set_state(AppState { loading: true, user: None });
+///         let store = self.store.clone();
+///         spawn_action(async move {
+///             let user = fetch_user().await;
+///             store.set_state(AppState { loading: false, user: Some(user) });
+///         });
+///     }
+/// }
+///
+/// async fn fetch_user() -> String {
+///     "Alice".to_string()
+/// }
+///
+/// let service = ServiceContext::new(UserService {
+///     store: StoreContext::new(AppState { loading: false, user: None }),
+/// });
+/// service.load_user();
+/// ```
+pub fn spawn_action(action: impl Future<Output = ()> + 'static) {
+    wasm_bindgen_futures::spawn_local(action);
+}
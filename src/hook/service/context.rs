@@ -1,7 +1,14 @@
-use std::{ops::Deref, rc::Rc};
+use crate::Store;
+use std::{
+    any::type_name,
+    fmt::{self, Debug},
+    ops::Deref,
+    rc::Rc,
+};
 
 /// Context which holds a reference to the service `T`.
-pub struct ServiceContext<T> {
+/// `T` can be a trait object, so components can depend on an interface rather than a concrete service.
+pub struct ServiceContext<T: ?Sized> {
     pub service: Rc<T>,
 }
 
@@ -13,13 +20,98 @@ impl<T> ServiceContext<T> {
     }
 }
 
-impl<T> PartialEq for ServiceContext<T> {
+impl<T: ?Sized> ServiceContext<T> {
+    /// Creates a new `ServiceContext` from an already shared service, such as a trait object.
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// trait Greeter {
+    ///     fn greet(&self) -> String;
+    /// }
+    ///
+    /// struct EnglishGreeter;
+    ///
+    /// impl Greeter for EnglishGreeter {
+    ///     fn greet(&self) -> String {
+    ///         "Hello".to_string()
+    ///     }
+    /// }
+    ///
+    /// #[function_component]
+    /// fn App() -> Html {
+    ///     let service = ServiceContext::<dyn Greeter>::new_dyn(Rc::new(EnglishGreeter));
+    ///     html! {
+    ///         <ContextProvider<ServiceContext<dyn Greeter>> context={service}>
+    ///             <Greeting />
+    ///         </ContextProvider<ServiceContext<dyn Greeter>>>
+    ///     }
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Greeting() -> Html {
+    ///     let greeter = use_service::<dyn Greeter>();
+    ///     html! { greeter.greet() }
+    /// }
+    /// ```
+    pub fn new_dyn(service: Rc<T>) -> Self {
+        Self { service }
+    }
+}
+
+impl<T: ?Sized + 'static> ServiceContext<T> {
+    /// Subscribe to changes made to the given `store` for as long as the service is alive.
+    /// The `callback` receives the service along with the previous and current store states.
+    /// Once the last reference to the service is dropped, the subscription is dropped on the next change.
+    /// ```rust
+    /// use yewv::{ServiceContext, Store};
+    ///
+    /// struct AppService { }
+    ///
+    /// impl AppService {
+    ///     fn on_count_changed(&self, count: i32) { }
+    /// }
+    ///
+    /// let store = Store::new(0);
+    /// let service = ServiceContext::new(AppService { });
+    /// service.bind_subscription(&store, |service, _, count| service.on_count_changed(*count));
+    /// ```
+    pub fn bind_subscription<S: 'static>(
+        &self,
+        store: &Store<S>,
+        callback: impl Fn(&T, &S, &S) + 'static,
+    ) {
+        let service = Rc::downgrade(&self.service);
+        store.subscribe(move |prev, next| match service.upgrade() {
+            Some(service) => {
+                callback(&service, prev, next);
+                true
+            }
+            None => false,
+        });
+    }
+}
+
+impl<T: Default> Default for ServiceContext<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for ServiceContext<T> {
+    fn from(service: T) -> Self {
+        Self::new(service)
+    }
+}
+
+impl<T: ?Sized> PartialEq for ServiceContext<T> {
     fn eq(&self, other: &Self) -> bool {
         Rc::ptr_eq(&self.service, &other.service)
     }
 }
 
-impl<T> Clone for ServiceContext<T> {
+impl<T: ?Sized> Clone for ServiceContext<T> {
     fn clone(&self) -> Self {
         Self {
             service: self.service.clone(),
@@ -27,10 +119,126 @@ impl<T> Clone for ServiceContext<T> {
     }
 }
 
-impl<T> Deref for ServiceContext<T> {
+/// Print the service type name along with the address of the service, which identifies the context.
+impl<T: ?Sized> Debug for ServiceContext<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServiceContext")
+            .field("service", &type_name::<T>())
+            .field("address", &Rc::as_ptr(&self.service))
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Deref for ServiceContext<T> {
     type Target = Rc<T>;
 
     fn deref(&self) -> &Self::Target {
         &self.service
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    struct TestContext {
+        notified_values: Rc<RefCell<Vec<(i32, i32)>>>,
+        service: ServiceContext<()>,
+        store: Store<i32>,
+    }
+
+    fn setup() -> TestContext {
+        let store = Store::new(0);
+        let service = ServiceContext::new(());
+        let notified_values = Rc::new(RefCell::new(vec![]));
+        service.bind_subscription(&store, {
+            let notified_values = notified_values.clone();
+            move |_, prev, next| notified_values.borrow_mut().push((*prev, *next))
+        });
+        TestContext {
+            notified_values,
+            service,
+            store,
+        }
+    }
+
+    #[test]
+    fn eq_with_two_default_contexts_should_not_be_equal() {
+        //Given
+        let context = ServiceContext::<()>::default();
+        //When
+        let other = ServiceContext::<()>::default();
+        //Then
+        assert!(context != other);
+    }
+
+    #[test]
+    fn from_with_service_should_hold_service() {
+        //Given
+        //When
+        let context = ServiceContext::from(1);
+        //Then
+        assert_eq!(**context, 1);
+    }
+
+    #[test]
+    fn fmt_with_context_should_print_service_type_name() {
+        //Given
+        let context = ServiceContext::new(());
+        //When
+        let output = format!("{:?}", context);
+        //Then
+        assert!(output.starts_with("ServiceContext { service: \"()\", address: 0x"));
+    }
+
+    trait TestService {
+        fn value(&self) -> i32;
+    }
+
+    impl TestService for i32 {
+        fn value(&self) -> i32 {
+            *self
+        }
+    }
+
+    #[test]
+    fn new_dyn_with_trait_object_should_hold_service() {
+        //Given
+        //When
+        let context = ServiceContext::<dyn TestService>::new_dyn(Rc::new(1));
+        //Then
+        assert_eq!(context.value(), 1);
+    }
+
+    #[test]
+    fn eq_with_cloned_trait_object_context_should_be_equal() {
+        //Given
+        let context = ServiceContext::<dyn TestService>::new_dyn(Rc::new(1));
+        //When
+        let other = context.clone();
+        //Then
+        assert!(context == other);
+    }
+
+    #[test]
+    fn bind_subscription_with_service_alive_should_notify() {
+        //Given
+        let ctx = setup();
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1)]);
+    }
+
+    #[test]
+    fn bind_subscription_with_service_dropped_should_no_longer_notify() {
+        //Given
+        let ctx = setup();
+        drop(ctx.service);
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert!(ctx.notified_values.borrow().is_empty());
+    }
+}
@@ -1,7 +1,16 @@
+#[cfg(feature = "async")]
+mod action;
 mod context;
 
+use super::message::not_registered;
+#[cfg(feature = "async")]
+pub use action::spawn_action;
 pub use context::ServiceContext;
-use yew::{hook, use_context};
+use std::{any::type_name, marker::PhantomData};
+use yew::{
+    functional::{Hook, HookContext},
+    use_context,
+};
 
 /// Obtain a context for the given service `T`.
 /// ```rust
@@ -26,10 +35,61 @@ use yew::{hook, use_context};
 ///     }
 /// }
 /// ```
-#[hook]
-pub fn use_service<T>() -> ServiceContext<T>
+pub fn use_service<T>() -> impl Hook<Output = ServiceContext<T>>
 where
-    T: 'static,
+    T: ?Sized + 'static,
 {
-    use_context::<ServiceContext<T>>().expect("service was not registered.")
+    // Implemented without `#[hook]`, which doesn't support unsized types such as trait objects.
+    UseService(PhantomData)
+}
+
+struct UseService<T: ?Sized>(PhantomData<fn() -> ServiceContext<T>>);
+
+impl<T: ?Sized + 'static> Hook for UseService<T> {
+    type Output = ServiceContext<T>;
+
+    fn run(self, ctx: &mut HookContext) -> Self::Output {
+        use_context::<ServiceContext<T>>()
+            .run(ctx)
+            .unwrap_or_else(|| {
+                panic!(
+                    "{}",
+                    not_registered("use_service", "ServiceContext", type_name::<T>())
+                )
+            })
+    }
+}
+
+/// Obtain a context for the given service `T`, if registered.
+/// As opposed to `use_service`, `use_service_opt` returns `None` instead of panicking when no
+/// service context is registered in a parent component.
+/// ```rust
+/// use yew::prelude::*;
+/// use yewv::use_service_opt;
+///
+/// struct AppService { }
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     match use_service_opt::<AppService>() {
+///         Some(_) => html!{ "Service registered" },
+///         None => html!{ "No service registered" },
+///     }
+/// }
+/// ```
+pub fn use_service_opt<T>() -> impl Hook<Output = Option<ServiceContext<T>>>
+where
+    T: ?Sized + 'static,
+{
+    UseServiceOpt(PhantomData)
+}
+
+struct UseServiceOpt<T: ?Sized>(PhantomData<fn() -> ServiceContext<T>>);
+
+impl<T: ?Sized + 'static> Hook for UseServiceOpt<T> {
+    type Output = Option<ServiceContext<T>>;
+
+    fn run(self, ctx: &mut HookContext) -> Self::Output {
+        use_context::<ServiceContext<T>>().run(ctx)
+    }
 }
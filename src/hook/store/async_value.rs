@@ -0,0 +1,114 @@
+use super::Store;
+
+/// State of a value loaded asynchronously, such as remote data.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum AsyncValue<T, E = String> {
+    /// Loading didn't start yet.
+    #[default]
+    Idle,
+    /// Loading is in progress.
+    Loading,
+    /// Loading succeeded with the given value.
+    Ready(T),
+    /// Loading failed with the given error.
+    Failed(E),
+}
+
+impl<T, E> AsyncValue<T, E> {
+    /// Tell whether loading is in progress.
+    pub fn is_loading(&self) -> bool {
+        matches!(self, Self::Loading)
+    }
+
+    /// Give the loaded value, if loading succeeded.
+    pub fn data(&self) -> Option<&T> {
+        match self {
+            Self::Ready(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Give the loading error, if loading failed.
+    pub fn error(&self) -> Option<&E> {
+        match self {
+            Self::Failed(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl<T, E> Store<AsyncValue<T, E>> {
+    /// Set the store state as loading.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// #[function_component]
+    /// fn User() -> Html {
+    ///     let store = use_store::<AsyncValue<String>>();
+    ///     let loading = store.map(|state| state.is_loading());
+    ///     let state = store.map_ref(|state| state);
+    ///
+    ///     match (*loading, state.data(), state.error()) {
+    ///         (true, _, _) => html! { "Loading..." },
+    ///         (_, Some(user), _) => html! { user },
+    ///         (_, _, Some(error)) => html! { error },
+    ///         _ => html! {},
+    ///     }
+    /// }
+    ///
+    /// let store = Store::new(AsyncValue::<String>::Idle);
+    /// store.start_loading();
+    /// assert!(store.state().is_loading());
+    /// ```
+    pub fn start_loading(&self) {
+        self.set_state(AsyncValue::Loading);
+    }
+
+    /// Set the store state as ready with the loaded `data`.
+    pub fn set_ready(&self, data: T) {
+        self.set_state(AsyncValue::Ready(data));
+    }
+
+    /// Set the store state as failed with the given `error`.
+    pub fn set_failed(&self, error: E) {
+        self.set_state(AsyncValue::Failed(error));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_loading_with_idle_state_should_be_loading() {
+        //Given
+        let store = Store::<AsyncValue<i32>>::new(AsyncValue::Idle);
+        //When
+        store.start_loading();
+        //Then
+        assert!(store.state().is_loading());
+    }
+
+    #[test]
+    fn set_ready_with_loading_state_should_give_data() {
+        //Given
+        let store = Store::<AsyncValue<i32>>::new(AsyncValue::Loading);
+        //When
+        store.set_ready(1);
+        //Then
+        assert_eq!(store.state().data(), Some(&1));
+        assert!(!store.state().is_loading());
+    }
+
+    #[test]
+    fn set_failed_with_loading_state_should_give_error() {
+        //Given
+        let store = Store::<AsyncValue<i32>>::new(AsyncValue::Loading);
+        //When
+        store.set_failed("Not found".to_string());
+        //Then
+        assert_eq!(store.state().error(), Some(&"Not found".to_string()));
+        assert_eq!(store.state().data(), None);
+    }
+}
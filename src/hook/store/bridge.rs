@@ -0,0 +1,132 @@
+use super::StoreContext;
+use std::{
+    cell::{Cell, RefCell},
+    marker::PhantomData,
+    rc::Rc,
+};
+use yew::{html::Scope, Component};
+
+/// Subscription of a struct component to a store, sending a message to the component on every change.
+/// The component is unsubscribed once the bridge is dropped, typically along with the component.
+/// ```rust
+/// use yew::prelude::*;
+/// use yewv::{StoreBridge, StoreContext};
+///
+/// struct StoreState {
+///     value: i32
+/// }
+///
+/// enum Msg {
+///     ValueChanged(i32),
+/// }
+///
+/// struct Counter {
+///     value: i32,
+///     _bridge: StoreBridge<StoreState, Self>,
+/// }
+///
+/// impl Component for Counter {
+///     type Message = Msg;
+///     type Properties = ();
+///
+///     fn create(ctx: &Context<Self>) -> Self {
+///         let (context, _) = ctx
+///             .link()
+///             .context::<StoreContext<StoreState>>(Callback::noop())
+///             .expect("StoreContext<StoreState> should be registered.");
+///         let bridge = StoreBridge::new(ctx.link(), context, |state| Msg::ValueChanged(state.value));
+///         Self {
+///             value: bridge.state().value,
+///             _bridge: bridge,
+///         }
+///     }
+///
+///     fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+///         match msg {
+///             Msg::ValueChanged(value) => self.value = value,
+///         }
+///         true
+///     }
+///
+///     fn view(&self, _ctx: &Context<Self>) -> Html {
+///         html!{ { self.value } }
+///     }
+/// }
+/// ```
+pub struct StoreBridge<T, COMP> {
+    context: StoreContext<T>,
+    active: Rc<Cell<bool>>,
+    component: PhantomData<fn() -> COMP>,
+}
+
+impl<T: 'static, COMP: Component> StoreBridge<T, COMP> {
+    /// Subscribe the component of the `link` to the store, sending the message given by `map` on every change.
+    pub fn new(
+        link: &Scope<COMP>,
+        context: StoreContext<T>,
+        map: impl Fn(&T) -> COMP::Message + 'static,
+    ) -> Self {
+        Self::subscribe(link, context, move |state| Some(map(state)))
+    }
+
+    /// Subscribe the component of the `link` to the value selected from the store,
+    /// sending the message given by `map` only when the selected value changed.
+    pub fn with_selector<M: PartialEq + 'static>(
+        link: &Scope<COMP>,
+        context: StoreContext<T>,
+        select: impl Fn(&T) -> M + 'static,
+        map: impl Fn(&M) -> COMP::Message + 'static,
+    ) -> Self {
+        let current = RefCell::new(select(&context.state()));
+        Self::subscribe(link, context, move |state| {
+            let next = select(state);
+            if *current.borrow() == next {
+                return None;
+            }
+            let message = map(&next);
+            *current.borrow_mut() = next;
+            Some(message)
+        })
+    }
+
+    fn subscribe(
+        link: &Scope<COMP>,
+        context: StoreContext<T>,
+        message: impl Fn(&T) -> Option<COMP::Message> + 'static,
+    ) -> Self {
+        let active = Rc::new(Cell::new(true));
+        context.subscribe({
+            let (link, active) = (link.clone(), active.clone());
+            move |_, next| {
+                if !active.get() {
+                    return false;
+                }
+                if let Some(message) = message(next) {
+                    link.send_message(message);
+                }
+                true
+            }
+        });
+        Self {
+            context,
+            active,
+            component: PhantomData,
+        }
+    }
+
+    /// Give the current state of the store.
+    pub fn state(&self) -> Rc<T> {
+        self.context.state()
+    }
+
+    /// Give the context of the store the component is subscribed to.
+    pub fn context(&self) -> &StoreContext<T> {
+        &self.context
+    }
+}
+
+impl<T, COMP> Drop for StoreBridge<T, COMP> {
+    fn drop(&mut self) {
+        self.active.set(false);
+    }
+}
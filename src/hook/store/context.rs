@@ -1,38 +1,681 @@
 use super::Store;
-use std::{ops::Deref, rc::Rc};
+#[cfg(feature = "timers")]
+use gloo_render::request_animation_frame;
+#[cfg(any(feature = "timers", test))]
+use std::cell::RefCell;
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::{
+    any::type_name,
+    cell::{Cell, OnceCell},
+    fmt::{self, Debug},
+    marker::PhantomData,
+    ops::Deref,
+    rc::Rc,
+};
+
+/// When a store notifies its subscribers of changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotifyPolicy {
+    /// Notify subscribers synchronously on every change, as with `StoreContext::new`.
+    Immediate,
+    /// Notify subscribers once per microtask, as with `StoreContext::new_deferred`.
+    #[cfg(feature = "async")]
+    Microtask,
+    /// Notify subscribers at most once per animation frame, for stores changed many times per frame.
+    #[cfg(feature = "timers")]
+    AnimationFrame,
+}
 
 /// Context holding a reference to the store.
-pub struct StoreContext<T> {
-    pub(crate) store: Rc<super::Store<T>>,
+///
+/// The marker `K` tells apart several stores of the same state type registered side by side.
+/// Marked contexts are created with `marked` and obtained with `use_marked_store::<T, K>()`.
+pub struct StoreContext<T, K = ()> {
+    lazy: Rc<LazyStore<T>>,
+    marker: PhantomData<fn() -> K>,
+}
+
+/// Store created on first access when its context was created with `new_lazy`.
+struct LazyStore<T> {
+    store: OnceCell<Rc<Store<T>>>,
+    init: Cell<Option<Box<dyn FnOnce() -> T>>>,
 }
 
-impl<T> PartialEq for StoreContext<T> {
+impl<T, K> PartialEq for StoreContext<T, K> {
     fn eq(&self, other: &Self) -> bool {
-        Rc::ptr_eq(&self.store, &other.store)
+        Rc::ptr_eq(&self.lazy, &other.lazy)
+    }
+}
+
+impl<T, K> StoreContext<T, K> {
+    pub(crate) fn from_store(store: Rc<Store<T>>) -> Self {
+        Self {
+            lazy: Rc::new(LazyStore {
+                store: OnceCell::from(store),
+                init: Cell::new(None),
+            }),
+            marker: PhantomData,
+        }
+    }
+
+    /// Give an owned handle to the store, to capture it in `'static` closures such as in services.
+    /// ```rust
+    /// use yewv::StoreContext;
+    ///
+    /// let context = StoreContext::new(0);
+    /// let store = context.store();
+    /// let increment = move || store.set_state(*store.state() + 1);
+    /// increment();
+    /// assert_eq!(*context.state(), 1);
+    /// ```
+    pub fn store(&self) -> Rc<Store<T>> {
+        self.store_ref().clone()
+    }
+
+    /// Give the store, creating it on first access for contexts created with `new_lazy`.
+    pub(crate) fn store_ref(&self) -> &Rc<Store<T>> {
+        self.lazy.store.get_or_init(|| {
+            let init = self
+                .lazy
+                .init
+                .take()
+                .expect("Lazy store has no initializer.");
+            Rc::new(Store::new(init()))
+        })
+    }
+
+    /// Give a context to the same store, marked with `L`.
+    /// ```rust
+    /// use yewv::StoreContext;
+    ///
+    /// struct Sidebar;
+    /// struct Main;
+    ///
+    /// let sidebar = StoreContext::new(vec![1]).marked::<Sidebar>();
+    /// let main = StoreContext::new(vec![2]).marked::<Main>();
+    /// assert_eq!(*sidebar.state(), vec![1]);
+    /// assert_eq!(*main.state(), vec![2]);
+    /// ```
+    pub fn marked<L>(self) -> StoreContext<T, L> {
+        StoreContext {
+            lazy: self.lazy,
+            marker: PhantomData,
+        }
     }
 }
 
 impl<T> StoreContext<T> {
     /// Creates a new `StoreContext` with the given `initial_state`.
     pub fn new(initial_state: T) -> Self {
+        Self::from_store(Rc::new(Store::new(initial_state)))
+    }
+
+    /// Creates a new `StoreContext` with the given `initial_state`, running `setup` on the store before it is shared.
+    /// Middlewares and subscriptions added by `setup` are in place before any change is made to the store.
+    /// ```rust
+    /// use yewv::StoreContext;
+    ///
+    /// let store = StoreContext::new_with(0, |store| {
+    ///     store.add_middleware(|prev: &i32, next: &i32| println!("{} -> {}", prev, next));
+    /// });
+    /// store.set_state(1);
+    /// ```
+    pub fn new_with(initial_state: T, setup: impl FnOnce(&Store<T>)) -> Self {
+        let store = Store::new(initial_state);
+        setup(&store);
+        Self::from_store(Rc::new(store))
+    }
+
+    /// Creates a new `StoreContext` with the given `initial_state`, with room for `subs_hint` subscriptions.
+    /// Large applications subscribing many components to the same store avoid reallocating subscriptions as they mount.
+    pub fn new_with_capacity(initial_state: T, subs_hint: usize) -> Self {
+        Self::from_store(Rc::new(Store::with_capacity_hint(initial_state, subs_hint)))
+    }
+
+    /// Creates a new `StoreContext` with the given `initial_state`, recording up to `capacity` previous states.
+    /// ```rust
+    /// use yewv::StoreContext;
+    ///
+    /// let store = StoreContext::with_history(0, 10);
+    /// store.set_state(1);
+    /// assert!(store.can_undo());
+    /// store.undo();
+    /// assert_eq!(*store.state(), 0);
+    /// assert!(store.can_redo());
+    /// ```
+    pub fn with_history(initial_state: T, capacity: usize) -> Self {
+        Self::from_store(Rc::new(Store::with_history(initial_state, capacity)))
+    }
+}
+
+impl<T: 'static> StoreContext<T> {
+    /// Creates a new `StoreContext` with the given `initial_state`, notifying subscribers once per microtask.
+    /// The store state is updated immediately, but changes made within the same microtask are notified together,
+    /// with the state preceding the first change as previous state.
+    /// Middlewares `after` hooks are called once per batch as well.
+    ///
+    /// Stores created with `new` notify subscribers synchronously on every change.
+    /// ```rust,no_run
+    /// use yewv::StoreContext;
+    ///
+    /// let store = StoreContext::new_deferred(0);
+    /// store.set_state(1);
+    /// store.set_state(2);
+    /// assert_eq!(*store.state(), 2);
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn new_deferred(initial_state: T) -> Self {
+        let context = Self::new(initial_state);
+        let store = Rc::downgrade(context.store_ref());
+        context.defer_notifications(move || {
+            let store = store.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Some(store) = store.upgrade() {
+                    store.flush();
+                }
+            });
+        });
+        context
+    }
+
+    /// Creates a new `StoreContext` with the given `initial_state`, notifying subscribers according to the `policy`.
+    /// Whatever the policy, the store state is updated immediately and `state` always gives the latest state.
+    /// Pending notifications can be sent right away with `flush`, such as in tests.
+    ///
+    /// The `Microtask` policy requires the `async` feature and the `AnimationFrame` policy the `timers` feature.
+    /// ```rust,no_run
+    /// use yewv::{NotifyPolicy, StoreContext};
+    ///
+    /// let store = StoreContext::new_with_policy((0.0, 0.0), NotifyPolicy::Immediate);
+    /// store.set_state((1.0, 0.0));
+    /// store.set_state((2.0, 0.0));
+    /// assert_eq!(*store.state(), (2.0, 0.0));
+    /// store.flush();
+    /// ```
+    pub fn new_with_policy(initial_state: T, policy: NotifyPolicy) -> Self {
+        match policy {
+            NotifyPolicy::Immediate => Self::new(initial_state),
+            #[cfg(feature = "async")]
+            NotifyPolicy::Microtask => Self::new_deferred(initial_state),
+            #[cfg(feature = "timers")]
+            NotifyPolicy::AnimationFrame => {
+                let context = Self::new(initial_state);
+                let store = Rc::downgrade(context.store_ref());
+                // The pending frame is cancelled when the store is dropped.
+                let frame = RefCell::new(None);
+                context.defer_notifications(move || {
+                    let store = store.clone();
+                    *frame.borrow_mut() = Some(request_animation_frame(move |_| {
+                        if let Some(store) = store.upgrade() {
+                            store.flush();
+                        }
+                    }));
+                });
+                context
+            }
+        }
+    }
+
+    /// Creates a new `StoreContext` whose store is only created, calling `init`, once the store is first used.
+    /// Providing the context doesn't create the store, calling `use_store` or any store method does.
+    /// ```rust
+    /// use yewv::StoreContext;
+    ///
+    /// let store = StoreContext::new_lazy(|| vec![0; 1_000]);
+    /// let provided = store.clone();
+    /// assert!(store == provided);
+    /// assert_eq!(provided.state().len(), 1_000);
+    /// ```
+    pub fn new_lazy(init: impl FnOnce() -> T + 'static) -> Self {
         Self {
-            store: Rc::new(Store::new(initial_state)),
+            lazy: Rc::new(LazyStore {
+                store: OnceCell::new(),
+                init: Cell::new(Some(Box::new(init))),
+            }),
+            marker: PhantomData,
         }
     }
 }
 
-impl<T> Deref for StoreContext<T> {
+impl<T: 'static, K: 'static> StoreContext<T, K> {
+    /// Set the store next state once the given future resolves, running it in the background.
+    /// ```rust,no_run
+    /// use yewv::StoreContext;
+    ///
+    /// async fn fetch_count() -> i32 {
+    ///     1
+    /// }
+    ///
+    /// let store = StoreContext::new(0);
+    /// store.set_state_async(fetch_count());
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn set_state_async(&self, next_state: impl Future<Output = T> + 'static) {
+        let context = self.clone();
+        crate::spawn_action(async move { context.set_state(next_state.await) });
+    }
+
+    /// Creates a new `StoreContext` whose state is the value selected from this store state.
+    /// The derived store is only updated when the selected value changed.
+    ///
+    /// The derived context can be registered with `ContextProvider<StoreContext<M>>` and used with `use_store::<M>()`.
+    /// Once every clone of the derived context is dropped, it is detached from this store on the next change.
+    /// ```rust
+    /// use yewv::StoreContext;
+    ///
+    /// struct AppState {
+    ///     todos: Vec<(String, bool)>,
+    /// }
+    ///
+    /// let store = StoreContext::new(AppState { todos: vec![] });
+    /// let done = store.derive(|state| state.todos.iter().filter(|(_, done)| *done).count());
+    /// store.set_state(AppState { todos: vec![("Write docs".to_string(), true)] });
+    /// assert_eq!(*done.state(), 1);
+    /// ```
+    pub fn derive<M: PartialEq + 'static>(
+        &self,
+        selector: impl Fn(&T) -> M + 'static,
+    ) -> StoreContext<M> {
+        StoreContext::from_store(self.store_ref().derived(selector))
+    }
+
+    /// Creates a new `StoreContext` whose state is the value focused by `get` in this store state.
+    /// The lens store is only updated when the focused value changed.
+    /// Setting the lens store state writes it back into this store, building the next state with `set`.
+    ///
+    /// The lens context can be registered with `ContextProvider<StoreContext<M>>` and used with `use_store::<M>()`,
+    /// letting reusable components depend on a small part of the state only.
+    /// ```rust
+    /// use yewv::StoreContext;
+    ///
+    /// struct AppState {
+    ///     profile: String,
+    ///     count: i32,
+    /// }
+    ///
+    /// let store = StoreContext::new(AppState { profile: "Alice".to_string(), count: 0 });
+    /// let profile = store.lens(
+    ///     |state| &state.profile,
+    ///     |state, profile| AppState { profile, count: state.count },
+    /// );
+    /// profile.set_state("Bob".to_string());
+    /// assert_eq!(store.state().profile, "Bob");
+    /// ```
+    pub fn lens<M: Clone + PartialEq + 'static>(
+        &self,
+        get: impl Fn(&T) -> &M + 'static,
+        set: impl Fn(&T, M) -> T + 'static,
+    ) -> StoreContext<M> {
+        let get = Rc::new(get);
+        let lens = Rc::new(Store::new(get(&self.state_ref()).clone()));
+        self.subscribe({
+            let lens = Rc::downgrade(&lens);
+            let get = get.clone();
+            move |_, next| match lens.upgrade() {
+                Some(lens) => {
+                    let value = get(next);
+                    let changed = *value != **lens.state_ref();
+                    if changed {
+                        lens.set_state(value.clone());
+                    }
+                    true
+                }
+                None => false,
+            }
+        });
+        lens.subscribe({
+            let parent = self.clone();
+            move |_, next| {
+                let current = parent.state();
+                // Changes coming from the parent store are already written into it.
+                if get(&current) != next {
+                    parent.set_state(set(&current, next.clone()));
+                }
+                true
+            }
+        });
+        StoreContext::from_store(lens)
+    }
+}
+
+impl<T: Default> Default for StoreContext<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for StoreContext<T> {
+    fn from(initial_state: T) -> Self {
+        Self::new(initial_state)
+    }
+}
+
+impl<T, K> Deref for StoreContext<T, K> {
     type Target = Rc<Store<T>>;
 
     fn deref(&self) -> &Self::Target {
-        &self.store
+        self.store_ref()
+    }
+}
+
+/// Print the state type name along with the address identifying the context, and the marker type name.
+/// Printing a context doesn't create its store.
+impl<T, K> Debug for StoreContext<T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StoreContext")
+            .field("state", &type_name::<T>())
+            .field("address", &Rc::as_ptr(&self.lazy))
+            .field("marker", &type_name::<K>())
+            .finish()
     }
 }
 
-impl<T> Clone for StoreContext<T> {
+impl<T, K> Clone for StoreContext<T, K> {
     fn clone(&self) -> Self {
         Self {
-            store: self.store.clone(),
+            lazy: self.lazy.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestContext {
+        parent: StoreContext<(i32, i32)>,
+        derived: StoreContext<i32>,
+        derived_notifications: Rc<RefCell<usize>>,
+    }
+
+    fn setup() -> TestContext {
+        let parent = StoreContext::new((0, 0));
+        let derived = parent.derive(|state| state.0);
+        let derived_notifications = Rc::new(RefCell::new(0));
+        derived.subscribe({
+            let derived_notifications = derived_notifications.clone();
+            move |_, _| {
+                *derived_notifications.borrow_mut() += 1;
+                true
+            }
+        });
+        TestContext {
+            parent,
+            derived,
+            derived_notifications,
         }
     }
+
+    #[test]
+    fn default_with_default_state_should_create_default_state() {
+        //Given
+        //When
+        let context = StoreContext::<i32>::default();
+        //Then
+        assert_eq!(*context.state(), 0);
+    }
+
+    #[test]
+    fn new_with_policy_with_immediate_policy_should_notify_on_change() {
+        //Given
+        let context = StoreContext::new_with_policy(0, NotifyPolicy::Immediate);
+        let notified = Rc::new(Cell::new(false));
+        context.subscribe({
+            let notified = notified.clone();
+            move |_, _| {
+                notified.set(true);
+                true
+            }
+        });
+        //When
+        context.set_state(1);
+        //Then
+        assert!(notified.get());
+    }
+
+    #[test]
+    fn eq_with_two_default_contexts_should_not_be_equal() {
+        //Given
+        let context = StoreContext::<i32>::default();
+        //When
+        let other = StoreContext::<i32>::default();
+        //Then
+        assert!(context != other);
+    }
+
+    #[test]
+    fn from_with_initial_state_should_create_initial_state() {
+        //Given
+        //When
+        let context = StoreContext::from(1);
+        //Then
+        assert_eq!(*context.state(), 1);
+    }
+
+    #[test]
+    fn new_with_subscription_in_setup_should_notify_first_change() {
+        //Given
+        let notifications = Rc::new(RefCell::new(vec![]));
+        let context = StoreContext::new_with(0, |store| {
+            let notifications = notifications.clone();
+            store.subscribe(move |prev, next| {
+                notifications.borrow_mut().push((*prev, *next));
+                true
+            });
+        });
+        //When
+        context.set_state(1);
+        //Then
+        assert_eq!(*notifications.borrow(), &[(0, 1)]);
+    }
+
+    #[test]
+    fn fmt_with_context_should_print_state_type_name() {
+        //Given
+        let context = StoreContext::new(0);
+        //When
+        let output = format!("{:?}", context);
+        //Then
+        assert!(output.starts_with("StoreContext { state: \"i32\", address: 0x"));
+    }
+
+    #[test]
+    fn eq_with_marked_context_should_equal_marked_clone() {
+        //Given
+        let context = StoreContext::new(0).marked::<u8>();
+        //When
+        let other = context.clone();
+        //Then
+        assert!(context == other);
+    }
+
+    #[test]
+    fn marked_with_marker_should_share_store() {
+        //Given
+        let context = StoreContext::new(0);
+        //When
+        let marked = context.clone().marked::<u8>();
+        marked.set_state(1);
+        //Then
+        assert_eq!(*context.state(), 1);
+    }
+
+    fn setup_lazy() -> (StoreContext<i32>, Rc<Cell<usize>>) {
+        let init_count = Rc::new(Cell::new(0));
+        let context = StoreContext::new_lazy({
+            let init_count = init_count.clone();
+            move || {
+                init_count.set(init_count.get() + 1);
+                1
+            }
+        });
+        (context, init_count)
+    }
+
+    #[test]
+    fn new_lazy_with_context_cloned_and_compared_should_not_create_store() {
+        //Given
+        let (context, init_count) = setup_lazy();
+        //When
+        let other = context.clone();
+        let is_same = context == other;
+        let output = format!("{:?}", other);
+        //Then
+        assert!(is_same);
+        assert!(output.starts_with("StoreContext"));
+        assert_eq!(init_count.get(), 0);
+    }
+
+    #[test]
+    fn new_lazy_with_store_used_from_clones_should_create_store_once() {
+        //Given
+        let (context, init_count) = setup_lazy();
+        let other = context.clone().marked::<u8>();
+        //When
+        other.set_state(2);
+        let state = context.state();
+        //Then
+        assert_eq!(*state, 2);
+        assert_eq!(init_count.get(), 1);
+    }
+
+    #[test]
+    fn store_with_context_should_give_same_store() {
+        //Given
+        let context = StoreContext::new(0);
+        //When
+        let store = context.store();
+        //Then
+        assert!(Rc::ptr_eq(&store, &context));
+    }
+
+    #[test]
+    fn store_with_lazy_context_should_create_store() {
+        //Given
+        let (context, init_count) = setup_lazy();
+        //When
+        let store = context.store();
+        //Then
+        assert_eq!(*store.state(), 1);
+        assert_eq!(init_count.get(), 1);
+    }
+
+    #[test]
+    fn derive_with_selected_value_changed_should_update_derived_state() {
+        //Given
+        let ctx = setup();
+        //When
+        ctx.parent.set_state((1, 0));
+        //Then
+        assert_eq!(*ctx.derived.state(), 1);
+        assert_eq!(*ctx.derived_notifications.borrow(), 1);
+    }
+
+    #[test]
+    fn derive_with_selected_value_unchanged_should_not_notify_derived_store() {
+        //Given
+        let ctx = setup();
+        //When
+        ctx.parent.set_state((0, 1));
+        //Then
+        assert_eq!(*ctx.derived_notifications.borrow(), 0);
+    }
+
+    struct LensContext {
+        parent: StoreContext<(i32, i32)>,
+        lens: StoreContext<i32>,
+        parent_notifications: Rc<RefCell<usize>>,
+        lens_notifications: Rc<RefCell<usize>>,
+    }
+
+    fn count_notifications<T: 'static>(context: &StoreContext<T>) -> Rc<RefCell<usize>> {
+        let notifications = Rc::new(RefCell::new(0));
+        context.subscribe({
+            let notifications = notifications.clone();
+            move |_, _| {
+                *notifications.borrow_mut() += 1;
+                true
+            }
+        });
+        notifications
+    }
+
+    fn setup_lens() -> LensContext {
+        let parent = StoreContext::new((0, 0));
+        let lens = parent.lens(|state| &state.0, |state, value| (value, state.1));
+        LensContext {
+            parent_notifications: count_notifications(&parent),
+            lens_notifications: count_notifications(&lens),
+            parent,
+            lens,
+        }
+    }
+
+    #[test]
+    fn lens_with_focused_value_changed_should_update_lens_state() {
+        //Given
+        let ctx = setup_lens();
+        //When
+        ctx.parent.set_state((1, 0));
+        //Then
+        assert_eq!(*ctx.lens.state(), 1);
+        assert_eq!(*ctx.lens_notifications.borrow(), 1);
+    }
+
+    #[test]
+    fn lens_with_focused_value_unchanged_should_not_notify_lens_store() {
+        //Given
+        let ctx = setup_lens();
+        //When
+        ctx.parent.set_state((0, 1));
+        //Then
+        assert_eq!(*ctx.lens_notifications.borrow(), 0);
+    }
+
+    #[test]
+    fn lens_with_lens_state_set_should_write_parent_state() {
+        //Given
+        let ctx = setup_lens();
+        ctx.parent.set_state((0, 2));
+        //When
+        ctx.lens.set_state(1);
+        //Then
+        assert_eq!(*ctx.parent.state(), (1, 2));
+    }
+
+    #[test]
+    fn lens_with_lens_state_set_should_notify_each_store_once() {
+        //Given
+        let ctx = setup_lens();
+        //When
+        ctx.lens.set_state(1);
+        //Then
+        assert_eq!(*ctx.parent_notifications.borrow(), 1);
+        assert_eq!(*ctx.lens_notifications.borrow(), 1);
+    }
+
+    #[test]
+    fn lens_with_parent_state_set_should_notify_each_store_once() {
+        //Given
+        let ctx = setup_lens();
+        //When
+        ctx.parent.set_state((1, 0));
+        //Then
+        assert_eq!(*ctx.parent_notifications.borrow(), 1);
+        assert_eq!(*ctx.lens_notifications.borrow(), 1);
+    }
+
+    #[test]
+    fn derive_with_derived_context_dropped_should_detach_from_parent() {
+        //Given
+        let ctx = setup();
+        let sub_count = ctx.parent.subscriber_count();
+        drop(ctx.derived);
+        //When
+        ctx.parent.set_state((1, 0));
+        //Then
+        assert_eq!(ctx.parent.subscriber_count(), sub_count - 1);
+    }
 }
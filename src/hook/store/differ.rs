@@ -0,0 +1,137 @@
+use super::{Store, StoreContext};
+use std::rc::Rc;
+
+/// Strategy deciding whether a state transition changes the store state.
+/// Transitions which don't change the state are cancelled: the state is kept and subscribers are not notified.
+/// The store consults it on every commit and at the end of each batch of deferred notifications, so hook
+/// subscriptions, including the `map_ref` and `watch_ref` comparisons, only run for changes the differ reports.
+/// ```rust
+/// use std::hash::{DefaultHasher, Hash, Hasher};
+/// use yewv::{Differ, StoreContext};
+///
+/// struct HashDiffer;
+///
+/// impl<T: Hash> Differ<T> for HashDiffer {
+///     fn changed(&self, prev: &T, next: &T) -> bool {
+///         let hash = |state: &T| {
+///             let mut hasher = DefaultHasher::new();
+///             state.hash(&mut hasher);
+///             hasher.finish()
+///         };
+///         hash(prev) != hash(next)
+///     }
+/// }
+///
+/// let store = StoreContext::new_with_differ(vec![1, 2], HashDiffer);
+/// store.set_state(vec![1, 2]);
+/// assert_eq!(store.version(), 0);
+/// ```
+pub trait Differ<T> {
+    /// Tell whether `next` differs from `prev`.
+    fn changed(&self, prev: &T, next: &T) -> bool;
+}
+
+/// Differ comparing states with `PartialEq`.
+pub struct PartialEqDiffer;
+
+impl<T: PartialEq> Differ<T> for PartialEqDiffer {
+    fn changed(&self, prev: &T, next: &T) -> bool {
+        prev != next
+    }
+}
+
+/// Closures are differs returning whether the state changed.
+impl<T, F: Fn(&T, &T) -> bool> Differ<T> for F {
+    fn changed(&self, prev: &T, next: &T) -> bool {
+        self(prev, next)
+    }
+}
+
+impl<T: 'static> StoreContext<T> {
+    /// Creates a new `StoreContext` with the given `initial_state`, using `differ` to detect state changes.
+    /// Setting a state which `differ` considers unchanged keeps the current state and doesn't notify subscribers.
+    ///
+    /// Stores created with `new` consider every new state as a change.
+    /// ```rust
+    /// use yewv::{PartialEqDiffer, StoreContext};
+    ///
+    /// let store = StoreContext::new_with_differ(0, PartialEqDiffer);
+    /// store.set_state(0);
+    /// assert_eq!(store.version(), 0);
+    /// store.set_state(1);
+    /// assert_eq!(store.version(), 1);
+    /// ```
+    pub fn new_with_differ(initial_state: T, differ: impl Differ<T> + 'static) -> Self {
+        Self::from_store(Rc::new(Store::with_differ(initial_state, differ)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    fn setup(
+        differ: impl Differ<(i32, i32)> + 'static,
+    ) -> (StoreContext<(i32, i32)>, Rc<RefCell<usize>>) {
+        let context = StoreContext::new_with_differ((0, 0), differ);
+        let notifications = Rc::new(RefCell::new(0));
+        context.subscribe({
+            let notifications = notifications.clone();
+            move |_, _| {
+                *notifications.borrow_mut() += 1;
+                true
+            }
+        });
+        (context, notifications)
+    }
+
+    #[test]
+    fn set_state_with_equal_state_should_not_notify() {
+        //Given
+        let (context, notifications) = setup(PartialEqDiffer);
+        let state = context.state();
+        //When
+        context.set_state((0, 0));
+        //Then
+        assert!(Rc::ptr_eq(&context.state(), &state));
+        assert_eq!(*notifications.borrow(), 0);
+    }
+
+    #[test]
+    fn set_state_with_different_state_should_notify() {
+        //Given
+        let (context, notifications) = setup(PartialEqDiffer);
+        //When
+        context.set_state((1, 0));
+        //Then
+        assert_eq!(*context.state(), (1, 0));
+        assert_eq!(*notifications.borrow(), 1);
+    }
+
+    #[test]
+    fn set_state_with_closure_differ_should_only_notify_observed_changes() {
+        //Given
+        let (context, notifications) =
+            setup(|prev: &(i32, i32), next: &(i32, i32)| prev.0 != next.0);
+        //When
+        context.set_state((0, 1));
+        context.set_state((1, 1));
+        //Then
+        assert_eq!(*context.state(), (1, 1));
+        assert_eq!(*notifications.borrow(), 1);
+    }
+
+    #[test]
+    fn flush_with_batch_ending_on_equal_state_should_not_notify() {
+        //Given
+        let (context, notifications) = setup(PartialEqDiffer);
+        context.defer_notifications(|| ());
+        context.set_state((1, 0));
+        context.set_state((0, 0));
+        //When
+        context.flush();
+        //Then
+        assert_eq!(*notifications.borrow(), 0);
+    }
+}
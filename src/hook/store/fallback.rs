@@ -0,0 +1,58 @@
+use super::StoreContext;
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+};
+
+thread_local! {
+    /// Stores created by `use_store_or_init` when no store is registered, one per state type.
+    static FALLBACK_STORES: RefCell<HashMap<TypeId, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// Give the fallback store of the state `T`, creating it with `init` on first access.
+pub(crate) fn fallback_store<T: 'static>(init: impl FnOnce() -> T) -> StoreContext<T> {
+    FALLBACK_STORES.with(|stores| {
+        if let Some(context) = stores.borrow().get(&TypeId::of::<T>()) {
+            return context
+                .downcast_ref::<StoreContext<T>>()
+                .expect("Fallback store should be registered under its state type.")
+                .clone();
+        }
+        // Initialized outside of the borrow, as `init` may create fallback stores of other types.
+        let context = StoreContext::new(init());
+        stores
+            .borrow_mut()
+            .insert(TypeId::of::<T>(), Box::new(context.clone()));
+        context
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct CounterState(i32);
+    struct LabelState(&'static str);
+
+    #[test]
+    fn fallback_store_called_twice_should_share_store_and_init_once() {
+        //Given
+        let first = fallback_store(|| CounterState(1));
+        //When
+        let second = fallback_store(|| CounterState(2));
+        //Then
+        assert_eq!(first, second);
+        assert_eq!(second.state().0, 1);
+    }
+
+    #[test]
+    fn fallback_store_with_other_state_type_should_create_other_store() {
+        //Given
+        fallback_store(|| CounterState(1));
+        //When
+        let label = fallback_store(|| LabelState("label"));
+        //Then
+        assert_eq!(label.state().0, "label");
+    }
+}
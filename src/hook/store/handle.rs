@@ -1,15 +1,123 @@
-use crate::{Store, StoreContext};
+#[cfg(feature = "timers")]
+use super::timed::{ThrottleMode, Timed};
+use super::{
+    keyed::{KeyedSlot, SlotKey},
+    vec_diff::{keyed_diff, KeyedDiff},
+};
+use crate::{Selector, Store, StoreContext};
+#[cfg(feature = "timers")]
+use std::time::Duration;
 use std::{
-    any::Any,
+    any::{type_name, Any},
     cell::{Ref, RefCell},
+    collections::HashMap,
+    fmt::{self, Debug},
+    hash::Hash,
     ops::Deref,
+    panic::Location,
     rc::Rc,
 };
 
 pub(crate) struct Subscriptions<T> {
     pub(crate) states: Vec<Rc<dyn Any>>,
-    pub(crate) subscriptions: Vec<Box<dyn (Fn(Rc<dyn Any>, &T) -> Rc<dyn Any>)>>,
+    pub(crate) subscriptions: Vec<Box<dyn (Fn(Rc<dyn Any>, &Rc<T>, &Rc<T>) -> Rc<dyn Any>)>>,
     pub(crate) ref_subscriptions: Vec<Box<dyn (Fn(&T, &T) -> bool)>>,
+    /// Origin of each state, to tell hooks apart when they are called conditionally.
+    origins: Vec<Origin>,
+    /// States of keyed hooks, which may be called conditionally or in loops.
+    pub(crate) keyed: HashMap<SlotKey, KeyedSlot<T>>,
+    /// Re-render the component outside of store notifications, such as once a timer elapsed.
+    pub(crate) renderer: Option<Rc<dyn Fn()>>,
+}
+
+/// Call site of the hook owning a state, type of the state and label given to the hook.
+struct Origin {
+    location: &'static Location<'static>,
+    type_name: &'static str,
+    label: Option<&'static str>,
+}
+
+impl<T> Subscriptions<T> {
+    /// Start a new render, dropping the states of hooks which were not called on the last render.
+    pub(crate) fn start_render(&mut self) {
+        self.states.truncate(self.subscriptions.len());
+        self.origins.truncate(self.subscriptions.len());
+        self.subscriptions.clear();
+        self.ref_subscriptions.clear();
+        self.keyed
+            .retain(|_, slot| slot.subscription.take().is_some());
+    }
+
+    /// Give the state of the hook being called, or `None` when the hook has no state yet.
+    /// When another hook was called at this position on the last render, as happens with hooks called conditionally,
+    /// the states from this position onward are dropped and initialized again.
+    #[track_caller]
+    fn current_state<S: 'static>(&mut self) -> Option<Rc<S>> {
+        let index = self.subscriptions.len();
+        let state = self.states.get(index)?;
+        if self.origins[index].location == Location::caller() {
+            if let Ok(state) = state.clone().downcast() {
+                return Some(state);
+            }
+        }
+        self.states.truncate(index);
+        self.origins.truncate(index);
+        None
+    }
+
+    /// Replace the state of the hook being called, once given by `current_state` or recorded with `push_state`.
+    fn replace_current_state(&mut self, state: Rc<dyn Any>) {
+        let index = self.subscriptions.len();
+        self.states[index] = state;
+    }
+
+    /// Record the state of the hook being called.
+    #[track_caller]
+    fn push_state<S: 'static>(&mut self, state: Rc<S>) {
+        self.states.push(state);
+        self.origins.push(Origin {
+            location: Location::caller(),
+            type_name: type_name::<S>(),
+            label: None,
+        });
+    }
+
+    /// Label the state of the hook called last, to tell it apart when it requests a render.
+    fn label_last(&mut self, label: &'static str) {
+        let index = self.subscriptions.len() - 1;
+        self.origins[index].label = Some(label);
+    }
+
+    /// Give the label of the hook owning the state at `index`, if any.
+    #[cfg_attr(not(all(debug_assertions, feature = "logger")), allow(dead_code))]
+    pub(crate) fn label(&self, index: usize) -> Option<&'static str> {
+        self.origins.get(index).and_then(|origin| origin.label)
+    }
+
+    /// Give the function re-rendering the component, doing nothing when the hooks are not bound to a component.
+    #[cfg_attr(not(feature = "timers"), allow(dead_code))]
+    fn renderer(&self) -> Rc<dyn Fn()> {
+        self.renderer.clone().unwrap_or_else(|| Rc::new(|| ()))
+    }
+}
+
+impl<T> Default for Subscriptions<T> {
+    fn default() -> Self {
+        Self {
+            states: vec![],
+            subscriptions: vec![],
+            ref_subscriptions: vec![],
+            origins: vec![],
+            keyed: HashMap::new(),
+            renderer: None,
+        }
+    }
+}
+
+/// State of an `on_change` hook, with the last value selected and the effect of the last render.
+struct OnChange<W> {
+    current: RefCell<W>,
+    effect: RefCell<Option<Box<dyn Fn(&W)>>>,
 }
 
 /// Handle exposing custom hooks to subscribe to the store.
@@ -40,33 +148,394 @@ impl<T: 'static> UseStoreHandle<T> {
     ///     html!{ { value } }
     /// }
     /// ```
+    #[track_caller]
     pub fn map<M: PartialEq + 'static>(&self, map: impl Fn(&T) -> M + 'static) -> Rc<M> {
-        let mut subs = self.subscriptions.borrow_mut();
-        let current_index = subs.subscriptions.len();
-        let value = match subs.states.get(current_index) {
-            Some(s) => s
-                .clone()
-                .downcast()
-                .expect("Store map was called in a different order."),
-            None => {
-                let state = Rc::new(map(&self.state_ref()));
-                subs.states.push(state.clone());
-                state
-            }
-        };
-        subs.subscriptions.push(Box::new(move |prev, next| {
+        self.map_with(map, M::eq)
+    }
+
+    /// (Hook) Subscribe to the store and return the value mapped, using `compare` to compare mapped values.
+    /// `compare` receives the previous and next values, in that order, and returns `true` when they are equal.
+    /// As opposed to `map`, the mapped value does not need to implement `PartialEq`.
+    /// `map_with` is a hook and should **not** be called inside loops, conditions or callbacks.
+    ///
+    /// A change to the observed value, according to `compare`, will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     value: f64
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let value = store.map_with(|state| state.value, |prev, next| (prev - next).abs() < 0.01);
+    ///     
+    ///     html!{ { value } }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn map_with<M: 'static>(
+        &self,
+        map: impl Fn(&T) -> M + 'static,
+        compare: impl Fn(&M, &M) -> bool + 'static,
+    ) -> Rc<M> {
+        let value = self.state_slot(&map);
+        self.push_subscription("map", move |prev: Rc<M>, _, next| {
             let next = map(next);
-            let prev = prev
-                .downcast::<M>()
-                .expect("Store map was called in a different order.");
-            if next.ne(&prev) {
+            if !compare(&prev, &next) {
                 return Rc::new(next);
             }
             prev
-        }));
+        });
+        value
+    }
+
+    /// (Hook) Subscribe to the store and return the value mapped, as with `map`, naming the subscription `label`.
+    /// With the `logger` feature in debug builds, the `label` is logged with the `log` crate at debug level every time
+    /// the subscription requests a render, to find out why a component rendered. Without it, the `label` has no effect.
+    /// `map_labeled` is a hook and should **not** be called inside loops, conditions or callbacks.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     value: i32
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let value = store.map_labeled("value", |state| state.value);
+    ///     
+    ///     html!{ { value } }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn map_labeled<M: PartialEq + 'static>(
+        &self,
+        label: &'static str,
+        map: impl Fn(&T) -> M + 'static,
+    ) -> Rc<M> {
+        let value = self.map(map);
+        self.subscriptions.borrow_mut().label_last(label);
+        value
+    }
+
+    /// (Hook) Subscribe to the store and return the value of the given `selector`.
+    /// The selector value is computed once per state, no matter how many components use it.
+    /// `map_selector` is a hook and should **not** be called inside loops, conditions or callbacks.
+    ///
+    /// A change to the observed value will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     items: Vec<i32>
+    /// }
+    ///
+    /// #[derive(Properties, PartialEq)]
+    /// struct Props {
+    ///     even_items: Selector<StoreState, Vec<i32>>,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test(props: &Props) -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let even_items = store.map_selector(&props.even_items);
+    ///     
+    ///     html!{ for even_items.iter() }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn map_selector<M: PartialEq + 'static>(&self, selector: &Selector<T, M>) -> Rc<M> {
+        let value = self.state_slot(|_| selector.get(&self.state()));
+        let selector = selector.clone();
+        self.push_subscription("map_selector", move |current: Rc<Rc<M>>, _, next| {
+            let next = selector.get(next);
+            if !Rc::ptr_eq(&*current, &next) && **current != *next {
+                return Rc::new(next);
+            }
+            current
+        });
+        (*value).clone()
+    }
+
+    /// (Hook) Subscribe to the store and return the shared value mapped, comparing mapped values by reference.
+    /// As opposed to `map`, the mapped value does not need to implement `PartialEq` and is never compared deeply,
+    /// which suits large immutable structures shared by the state.
+    /// `map_rc` is a hook and should **not** be called inside loops, conditions or callbacks.
+    ///
+    /// Mapping to a different `Rc` will re-render the component, even when both values are equal.
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     catalog: Rc<Vec<String>>
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let catalog = store.map_rc(|state| state.catalog.clone());
+    ///     
+    ///     html!{ for catalog.iter() }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn map_rc<M: 'static>(&self, map: impl Fn(&T) -> Rc<M> + 'static) -> Rc<M> {
+        let value = self.state_slot(&map);
+        self.push_subscription("map_rc", move |current: Rc<Rc<M>>, _, next| {
+            let next = map(next);
+            if !Rc::ptr_eq(&*current, &next) {
+                return Rc::new(next);
+            }
+            current
+        });
+        (*value).clone()
+    }
+
+    /// (Hook) Subscribe to the store and return the value mapped while `active` holds, or `None` otherwise.
+    /// The value is neither mapped nor compared while `active` doesn't hold, which avoids wasted work
+    /// for values only relevant in some modes of the application.
+    /// `map_while` is a hook and should **not** be called inside loops, conditions or callbacks.
+    ///
+    /// A change to `active`, or to the observed value while active, will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     editing: bool,
+    ///     draft: String,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let draft_length = store.map_while(|state| state.editing, |state| state.draft.len());
+    ///
+    ///     match draft_length {
+    ///         Some(length) => html!{ { length } },
+    ///         None => html!{},
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn map_while<M: PartialEq + 'static>(
+        &self,
+        active: impl Fn(&T) -> bool + 'static,
+        map: impl Fn(&T) -> M + 'static,
+    ) -> Option<Rc<M>> {
+        let value = self.state_slot(|state| active(state).then(|| Rc::new(map(state))));
+        self.push_subscription("map_while", move |current: Rc<Option<Rc<M>>>, _, next| {
+            let next = active(next).then(|| map(next));
+            match (&*current, next) {
+                (Some(current_value), Some(next)) if **current_value == next => current,
+                (None, None) => current,
+                (_, next) => Rc::new(next.map(Rc::new)),
+            }
+        });
+        (*value).clone()
+    }
+
+    /// (Hook) Subscribe to the store and return the value mapped from the previous and next states.
+    /// On the first render, both the previous and next states are the current state.
+    /// `map_delta` is a hook and should **not** be called inside loops, conditions or callbacks.
+    ///
+    /// A change to the observed value will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     velocity: i32
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let acceleration = store.map_delta(|prev, next| next.velocity - prev.velocity);
+    ///     
+    ///     html!{ { acceleration } }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn map_delta<M: PartialEq + 'static>(&self, map: impl Fn(&T, &T) -> M + 'static) -> Rc<M> {
+        let value = self.state_slot(|state| map(state, state));
+        self.push_subscription("map_delta", move |current: Rc<M>, prev, next| {
+            let next = map(prev, next);
+            if next.ne(&current) {
+                return Rc::new(next);
+            }
+            current
+        });
         value
     }
 
+    /// (Hook) Subscribe to a collection of the store and return the keys of the items inserted, removed and updated
+    /// since the last render. Items are matched by `key`, and an item is updated when its `value` changed.
+    /// On the first render, the diff is empty.
+    /// `map_vec` is a hook and should **not** be called inside loops, conditions or callbacks.
+    ///
+    /// A change to the collection, such as an item inserted, removed or updated, will re-render the component.
+    /// Reordering items or changing fields outside of `value` won't.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct Row {
+    ///     id: u32,
+    ///     label: String,
+    /// }
+    ///
+    /// struct StoreState {
+    ///     rows: Vec<Row>
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let diff = store.map_vec(|state| &state.rows, |row| row.id, |row| row.label.clone());
+    ///     
+    ///     html!{ format!("{} rows updated", diff.updated.len()) }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn map_vec<I, K, V>(
+        &self,
+        items: impl Fn(&T) -> &Vec<I> + 'static,
+        key: impl Fn(&I) -> K + 'static,
+        value: impl Fn(&I) -> V + 'static,
+    ) -> Rc<KeyedDiff<K>>
+    where
+        K: Eq + Hash + 'static,
+        V: PartialEq + 'static,
+    {
+        let slot = self.state_slot(|_| RefCell::new(self.state()));
+        let current = self.state();
+        let diff = keyed_diff(items(&slot.borrow()), items(&current), &key, &value);
+        *slot.borrow_mut() = current;
+        self.push_subscription("map_vec", move |base: Rc<RefCell<Rc<T>>>, _, next| {
+            if keyed_diff(items(&base.borrow()), items(next), &key, &value).is_empty() {
+                return base;
+            }
+            // The base stays the state of the last render, as the diff is computed from it.
+            let base = base.borrow().clone();
+            Rc::new(RefCell::new(base))
+        });
+        Rc::new(diff)
+    }
+
+    /// (Hook) Subscribe to the store and return the value computed from the state, recomputed only when `key` changes.
+    /// As opposed to `map`, the computed value does not need to implement `PartialEq`,
+    /// which makes `memo` suited for expensive derivations such as sorting a large list.
+    /// `memo` is a hook and should **not** be called inside loops, conditions or callbacks.
+    ///
+    /// A change to the observed key will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     revision: u32,
+    ///     items: Vec<String>,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let sorted = store.memo(|state| state.revision, |state| {
+    ///         let mut items = state.items.clone();
+    ///         items.sort();
+    ///         items
+    ///     });
+    ///     
+    ///     html!{ for sorted.iter() }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn memo<K: PartialEq + 'static, M: 'static>(
+        &self,
+        key: impl Fn(&T) -> K + 'static,
+        compute: impl Fn(&T) -> M + 'static,
+    ) -> Rc<M> {
+        let memo = self.state_slot(|state| (key(state), Rc::new(compute(state))));
+        self.push_subscription("memo", move |current: Rc<(K, Rc<M>)>, _, next| {
+            let next_key = key(next);
+            if next_key != current.0 {
+                return Rc::new((next_key, Rc::new(compute(next))));
+            }
+            current
+        });
+        memo.1.clone()
+    }
+
+    /// (Hook) Subscribe to the store and return the value mapped with the given `deps`, such as props.
+    /// As opposed to `map`, the value is mapped again as soon as `deps` differ from the ones of the last render,
+    /// instead of keeping the value mapped with the previous `deps` until the next change of the store.
+    /// `map_with_deps` is a hook and should **not** be called inside loops, conditions or callbacks.
+    ///
+    /// A change to the observed value will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     items: Vec<String>
+    /// }
+    ///
+    /// #[derive(Properties, PartialEq)]
+    /// struct Props {
+    ///     index: usize,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test(props: &Props) -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let item = store.map_with_deps(props.index, |state, index| state.items.get(*index).cloned());
+    ///     
+    ///     html!{ { item.as_deref().unwrap_or_default() } }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn map_with_deps<D: PartialEq + 'static, M: PartialEq + 'static>(
+        &self,
+        deps: D,
+        map: impl Fn(&T, &D) -> M + 'static,
+    ) -> Rc<M> {
+        let mut deps = Some(deps);
+        let mut current = self.state_slot(|state| {
+            let deps = deps.take().expect("Deps are only taken once.");
+            let value = Rc::new(map(state, &deps));
+            (Rc::new(deps), value)
+        });
+        // Deps are left when the state was mapped on a previous render, possibly with other deps.
+        if let Some(deps) = deps.filter(|deps| *current.0 != *deps) {
+            let value = Rc::new(map(&self.state_ref(), &deps));
+            current = Rc::new((Rc::new(deps), value));
+            self.subscriptions
+                .borrow_mut()
+                .replace_current_state(current.clone());
+        }
+        let deps = current.0.clone();
+        self.push_subscription(
+            "map_with_deps",
+            move |current: Rc<(Rc<D>, Rc<M>)>, _, next| {
+                let next = map(next, &deps);
+                if *current.1 != next {
+                    return Rc::new((current.0.clone(), Rc::new(next)));
+                }
+                current
+            },
+        );
+        current.1.clone()
+    }
+
     /// Subscribe to the store and return a reference to the value mapped.
     /// A change to the observed value will re-render the component.
     /// ```rust
@@ -94,74 +563,1312 @@ impl<T: 'static> UseStoreHandle<T> {
         value
     }
 
-    /// Subscribe to a specific store value.
-    /// A change to the observed value will re-render the component.
+    /// Subscribe to the store and return a reference to the value mapped, if any.
+    /// As opposed to `map_ref`, `map_ref_opt` suits values which may be missing, such as an index out of bounds or an absent key,
+    /// without panicking when comparing states.
+    ///
+    /// A change to the observed value, including it appearing or disappearing, will re-render the component.
     /// ```rust
     /// use yew::prelude::*;
     /// use yewv::*;
     ///
     /// struct StoreState {
-    ///     value: i32
+    ///     items: Vec<String>
     /// }
     ///
     /// #[function_component]
     /// fn Test() -> Html {
     ///     let store = use_store::<StoreState>();
-    ///     store.watch_ref(|state| &state.value);
+    ///     let first = store.map_ref_opt(|state| state.items.first());
+    ///     let label = first.as_deref().cloned().unwrap_or_else(|| "No items".to_string());
     ///     
-    ///     html!{ { store.state().value } }
+    ///     html!{ { label } }
     /// }
     /// ```
-    pub fn watch_ref<W: PartialEq>(&self, watch: impl Fn(&T) -> &W + 'static) {
+    pub fn map_ref_opt<M: PartialEq>(
+        &self,
+        map: impl Fn(&T) -> Option<&M> + 'static,
+    ) -> Option<Ref<'_, M>> {
+        let value = Ref::filter_map(self.state_ref(), |s| map(s)).ok();
         self.subscriptions
             .borrow_mut()
             .ref_subscriptions
-            .push(Box::new(move |prev, next| watch(prev) != watch(next)));
+            .push(Box::new(move |prev, next| map(prev) != map(next)));
+        value
     }
 
-    /// (Hook) Subscribe to a specific store value.
-    /// As opposed to `watch_ref`, `watch` is a hook and should **not** be called inside loops, conditions or callbacks.
+    /// Subscribe to the store and return a reference to the value mapped with the given `deps`, such as props.
+    /// As with `map_with_deps`, the value is always mapped with the `deps` of the current render.
     ///
-    /// If you only wish to watch a value owned by the store, you should use `watch_ref` instead.
     /// A change to the observed value will re-render the component.
     /// ```rust
     /// use yew::prelude::*;
     /// use yewv::*;
     ///
     /// struct StoreState {
-    ///     value: i32
+    ///     items: Vec<String>
+    /// }
+    ///
+    /// #[derive(Properties, PartialEq)]
+    /// struct Props {
+    ///     index: usize,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test(props: &Props) -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let item = store.map_ref_with_deps(props.index, |state, index| &state.items[*index]);
+    ///     
+    ///     html!{ { &*item } }
+    /// }
+    /// ```
+    pub fn map_ref_with_deps<D: 'static, M: PartialEq>(
+        &self,
+        deps: D,
+        map: impl for<'s> Fn(&'s T, &D) -> &'s M + 'static,
+    ) -> Ref<'_, M> {
+        let value = Ref::map(self.state_ref(), |s| map(s, &deps));
+        self.subscriptions
+            .borrow_mut()
+            .ref_subscriptions
+            .push(Box::new(move |prev, next| {
+                map(prev, &deps) != map(next, &deps)
+            }));
+        value
+    }
+
+    /// Subscribe to the store and return a reference to the value mapped, using `compare` to compare mapped values.
+    /// `compare` receives the previous and next values, in that order, and returns `true` when they are equal.
+    /// As opposed to `map_ref`, the mapped value does not need to implement `PartialEq`.
+    ///
+    /// A change to the observed value, according to `compare`, will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     ratio: f64
     /// }
     ///
     /// #[function_component]
     /// fn Test() -> Html {
     ///     let store = use_store::<StoreState>();
-    ///     store.watch(|state| state.value);
+    ///     let ratio = store.map_ref_with(|state| &state.ratio, |prev, next| (prev - next).abs() < 0.01);
     ///     
-    ///     html!{ { store.state().value } }
+    ///     html!{ { *ratio } }
     /// }
     /// ```
-    pub fn watch<W: PartialEq + 'static>(&self, watch: impl Fn(&T) -> W + 'static) {
-        let mut subs = self.subscriptions.borrow_mut();
-        if subs.states.len() == subs.subscriptions.len() {
-            subs.states.push(Rc::new(watch(&self.state_ref())));
-        }
-        subs.subscriptions.push(Box::new(move |prev, next| {
-            let next = watch(next);
-            let current = prev
-                .downcast::<W>()
-                .expect("Store watch was called in a different order.");
-            if next.ne(&current) {
-                return Rc::new(next);
-            }
-            current
-        }));
+    pub fn map_ref_with<M>(
+        &self,
+        map: impl Fn(&T) -> &M + 'static,
+        compare: impl Fn(&M, &M) -> bool + 'static,
+    ) -> Ref<'_, M> {
+        let value = Ref::map(self.state_ref(), |s| map(s));
+        self.subscriptions
+            .borrow_mut()
+            .ref_subscriptions
+            .push(Box::new(move |prev, next| !compare(map(prev), map(next))));
+        value
     }
-}
 
-impl<T> Deref for UseStoreHandle<T> {
+    /// Subscribe to two store values with a single subscription and return a reference to each of them.
+    /// Both references borrow the same state, so they are always consistent with each other.
+    /// A change to any of the observed values will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     first_name: String,
+    ///     last_name: String,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let (first_name, last_name) = store.map_ref2(|state| &state.first_name, |state| &state.last_name);
+    ///     
+    ///     html!{ format!("{} {}", *first_name, *last_name) }
+    /// }
+    /// ```
+    pub fn map_ref2<A: PartialEq, B: PartialEq>(
+        &self,
+        map_a: impl Fn(&T) -> &A + 'static,
+        map_b: impl Fn(&T) -> &B + 'static,
+    ) -> (Ref<'_, A>, Ref<'_, B>) {
+        let values = Ref::map_split(self.state_ref(), |s| (map_a(s), map_b(s)));
+        self.subscriptions
+            .borrow_mut()
+            .ref_subscriptions
+            .push(Box::new(move |prev, next| {
+                map_a(prev) != map_a(next) || map_b(prev) != map_b(next)
+            }));
+        values
+    }
+
+    /// (Hook) Subscribe to the store and return an owned copy of the value referenced.
+    /// As opposed to `map_ref`, the returned value doesn't borrow the store, so it can be moved into callbacks or futures.
+    /// The value is only cloned when the referenced value changed.
+    /// `clone_ref` is a hook and should **not** be called inside loops, conditions or callbacks.
+    ///
+    /// A change to the observed value will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     items: Vec<String>
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let items = store.clone_ref(|state| &state.items);
+    ///     let onclick = move |_| log(&items);
+    ///     
+    ///     html!{ <button {onclick}>{ "Log" }</button> }
+    /// }
+    ///
+    /// fn log(items: &[String]) { }
+    /// ```
+    #[track_caller]
+    pub fn clone_ref<M: PartialEq + Clone + 'static>(
+        &self,
+        f: impl Fn(&T) -> &M + 'static,
+    ) -> Rc<M> {
+        let value = self.state_slot(|state| f(state).clone());
+        self.push_subscription("clone_ref", move |current: Rc<M>, _, next| {
+            let next = f(next);
+            if *current != *next {
+                return Rc::new(next.clone());
+            }
+            current
+        });
+        value
+    }
+
+    /// Subscribe to a single entry of a collection and return a reference to it, if present.
+    /// Only the entry found with `key` is compared, so changes to other entries won't re-render the component.
+    /// A change to the observed entry, including its appearance or disappearance, will re-render the component.
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     items: HashMap<u32, String>
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let item = store.map_keyed(1, |state, key| state.items.get(key));
+    ///     
+    ///     html!{ { item.map(|item| item.clone()).unwrap_or_default() } }
+    /// }
+    /// ```
+    pub fn map_keyed<K: 'static, M: PartialEq>(
+        &self,
+        key: K,
+        map: impl for<'s> Fn(&'s T, &K) -> Option<&'s M> + 'static,
+    ) -> Option<Ref<M>> {
+        let value = Ref::filter_map(self.state_ref(), |s| map(s, &key)).ok();
+        self.subscriptions
+            .borrow_mut()
+            .ref_subscriptions
+            .push(Box::new(move |prev, next| {
+                map(prev, &key) != map(next, &key)
+            }));
+        value
+    }
+
+    /// Subscribe to the store and return the value mapped, holding its state under `key` instead of the call order.
+    /// As opposed to `map`, `map_by_key` can be called inside loops or conditions, as long as each `key` is used
+    /// once per render. The state of a key not used on a render is dropped.
+    /// Keys may be of any hashable type, such as a `&'static str` label naming a hook called conditionally.
+    ///
+    /// A change to the observed value will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     ids: Vec<u32>,
+    ///     names: Vec<String>,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let ids = store.map(|state| state.ids.clone());
+    ///     let names = ids.iter().map(|&id| {
+    ///         store.map_by_key(id, move |state| state.names.get(id as usize).cloned())
+    ///     });
+    ///
+    ///     html!{ for names.map(|name| html!{ <p>{ name.as_deref().unwrap_or_default() }</p> }) }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn map_by_key<K, M>(&self, key: K, map: impl Fn(&T) -> M + 'static) -> Rc<M>
+    where
+        K: Hash + Eq + Debug + 'static,
+        M: PartialEq + 'static,
+    {
+        let mut subs = self.subscriptions.borrow_mut();
+        let key = SlotKey::new(key);
+        let state = match subs.keyed.get(&key) {
+            Some(slot) if slot.subscription.is_some() => panic!(
+                "Store map_by_key was called more than once with the key {:?} in the same render.",
+                key
+            ),
+            Some(slot) => slot.state.clone(),
+            None => Rc::new(map(&self.state_ref())),
+        };
+        let value = state.clone().downcast::<M>().unwrap_or_else(|_| {
+            panic!(
+                "Store map_by_key was called with the key {:?} for values of different types.",
+                key
+            )
+        });
+        let subscription = Box::new(move |current: Rc<dyn Any>, _: &Rc<T>, next: &Rc<T>| {
+            let next = map(next);
+            match current.downcast_ref::<M>() {
+                Some(current_value) if *current_value == next => current,
+                _ => Rc::new(next) as Rc<dyn Any>,
+            }
+        });
+        subs.keyed.insert(
+            key,
+            KeyedSlot {
+                state,
+                subscription: Some(subscription),
+            },
+        );
+        value
+    }
+
+    /// Subscribe to a specific store value, holding its state under `key` instead of the call order.
+    /// As opposed to `watch`, `watch_by_key` can be called inside loops or conditions, as long as each `key` is used
+    /// once per render.
+    ///
+    /// A change to the observed value will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     show_total: bool,
+    ///     total: i32,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     if *store.map(|state| state.show_total) {
+    ///         store.watch_by_key("total", |state| state.total);
+    ///         return html!{ { store.state().total } };
+    ///     }
+    ///
+    ///     html!{}
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn watch_by_key<K, W>(&self, key: K, watch: impl Fn(&T) -> W + 'static)
+    where
+        K: Hash + Eq + Debug + 'static,
+        W: PartialEq + 'static,
+    {
+        self.map_by_key(key, watch);
+    }
+
+    /// Subscribe to a specific store value.
+    /// A change to the observed value will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     value: i32
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     store.watch_ref(|state| &state.value);
+    ///     
+    ///     html!{ { store.state().value } }
+    /// }
+    /// ```
+    pub fn watch_ref<W: PartialEq>(&self, watch: impl Fn(&T) -> &W + 'static) {
+        self.subscriptions
+            .borrow_mut()
+            .ref_subscriptions
+            .push(Box::new(move |prev, next| watch(prev) != watch(next)));
+    }
+
+    /// Subscribe to a specific store value, which may be missing.
+    /// As opposed to `watch_ref`, `watch_ref_opt` doesn't panic when comparing states with the value missing.
+    ///
+    /// A change to the observed value, including it appearing or disappearing, will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     items: Vec<String>
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     store.watch_ref_opt(|state| state.items.first());
+    ///
+    ///     html!{ { store.state().items.first().cloned().unwrap_or_default() } }
+    /// }
+    /// ```
+    pub fn watch_ref_opt<W: PartialEq>(&self, watch: impl Fn(&T) -> Option<&W> + 'static) {
+        self.subscriptions
+            .borrow_mut()
+            .ref_subscriptions
+            .push(Box::new(move |prev, next| watch(prev) != watch(next)));
+    }
+
+    /// Subscribe to a specific store value, using `compare` to compare observed values.
+    /// `compare` receives the previous and next values, in that order, and returns `true` when they are equal.
+    /// As opposed to `watch_ref`, the observed value does not need to implement `PartialEq`.
+    ///
+    /// A change to the observed value, according to `compare`, will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     ratio: f64
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     store.watch_ref_with(|state| &state.ratio, |prev, next| (prev - next).abs() < 0.01);
+    ///     
+    ///     html!{ { store.state().ratio } }
+    /// }
+    /// ```
+    pub fn watch_ref_with<W>(
+        &self,
+        watch: impl Fn(&T) -> &W + 'static,
+        compare: impl Fn(&W, &W) -> bool + 'static,
+    ) {
+        self.subscriptions
+            .borrow_mut()
+            .ref_subscriptions
+            .push(Box::new(move |prev, next| {
+                !compare(watch(prev), watch(next))
+            }));
+    }
+
+    /// Subscribe to two specific store values with a single subscription.
+    /// A change to any of the observed values will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     first: i32,
+    ///     second: i32,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     store.watch_ref2(|state| &state.first, |state| &state.second);
+    ///     let state = store.state();
+    ///     
+    ///     html!{ format!("{} and {}", state.first, state.second) }
+    /// }
+    /// ```
+    pub fn watch_ref2<A: PartialEq, B: PartialEq>(
+        &self,
+        watch_a: impl Fn(&T) -> &A + 'static,
+        watch_b: impl Fn(&T) -> &B + 'static,
+    ) {
+        self.subscriptions
+            .borrow_mut()
+            .ref_subscriptions
+            .push(Box::new(move |prev, next| {
+                watch_a(prev) != watch_a(next) || watch_b(prev) != watch_b(next)
+            }));
+    }
+
+    /// Subscribe to three specific store values with a single subscription.
+    /// A change to any of the observed values will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     first: i32,
+    ///     second: i32,
+    ///     third: i32,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     store.watch_ref3(|state| &state.first, |state| &state.second, |state| &state.third);
+    ///     let state = store.state();
+    ///     
+    ///     html!{ format!("{}, {} and {}", state.first, state.second, state.third) }
+    /// }
+    /// ```
+    pub fn watch_ref3<A: PartialEq, B: PartialEq, C: PartialEq>(
+        &self,
+        watch_a: impl Fn(&T) -> &A + 'static,
+        watch_b: impl Fn(&T) -> &B + 'static,
+        watch_c: impl Fn(&T) -> &C + 'static,
+    ) {
+        self.subscriptions
+            .borrow_mut()
+            .ref_subscriptions
+            .push(Box::new(move |prev, next| {
+                watch_a(prev) != watch_a(next)
+                    || watch_b(prev) != watch_b(next)
+                    || watch_c(prev) != watch_c(next)
+            }));
+    }
+
+    /// (Hook) Subscribe to a specific store value.
+    /// As opposed to `watch_ref`, `watch` is a hook and should **not** be called inside loops, conditions or callbacks.
+    ///
+    /// If you only wish to watch a value owned by the store, you should use `watch_ref` instead.
+    /// A change to the observed value will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     value: i32
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     store.watch(|state| state.value);
+    ///     
+    ///     html!{ { store.state().value } }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn watch<W: PartialEq + 'static>(&self, watch: impl Fn(&T) -> W + 'static) {
+        self.watch_with(watch, W::eq);
+    }
+
+    /// (Hook) Subscribe to a specific store value, using `compare` to compare observed values.
+    /// `compare` receives the previous and next values, in that order, and returns `true` when they are equal.
+    /// As opposed to `watch`, the observed value does not need to implement `PartialEq`.
+    /// `watch_with` is a hook and should **not** be called inside loops, conditions or callbacks.
+    ///
+    /// A change to the observed value, according to `compare`, will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     ratio: f64
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     store.watch_with(|state| state.ratio, |prev, next| (prev - next).abs() < 0.01);
+    ///     
+    ///     html!{ { store.state().ratio } }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn watch_with<W: 'static>(
+        &self,
+        watch: impl Fn(&T) -> W + 'static,
+        compare: impl Fn(&W, &W) -> bool + 'static,
+    ) {
+        self.state_slot(&watch);
+        self.push_subscription("watch", move |current: Rc<W>, _, next| {
+            let next = watch(next);
+            if !compare(&current, &next) {
+                return Rc::new(next);
+            }
+            current
+        });
+    }
+
+    /// (Hook) Subscribe to a specific store value and invoke `on_change` with its previous and next values,
+    /// in that order, every time it changes.
+    /// `on_change` isn't invoked on the first render, only for changes made afterwards.
+    /// `watch_changes` is a hook and should **not** be called inside loops, conditions or callbacks.
+    ///
+    /// A change to the observed value will re-render the component, use `watch_changes_silent` otherwise.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     count: i32
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     store.watch_changes(|state| state.count, |prev, next| animate_delta(next - prev));
+    ///     
+    ///     html!{ { store.state().count } }
+    /// }
+    ///
+    /// fn animate_delta(delta: i32) { }
+    /// ```
+    #[track_caller]
+    pub fn watch_changes<W: PartialEq + 'static>(
+        &self,
+        watch: impl Fn(&T) -> W + 'static,
+        on_change: impl Fn(&W, &W) + 'static,
+    ) {
+        self.state_slot(&watch);
+        self.push_subscription("watch_changes", move |current: Rc<W>, _, next| {
+            let next = watch(next);
+            if *current == next {
+                return current;
+            }
+            on_change(&current, &next);
+            Rc::new(next)
+        });
+    }
+
+    /// (Hook) Invoke `on_change` with the previous and next values of a specific store value, in that order,
+    /// every time it changes, **without** re-rendering the component.
+    /// `on_change` isn't invoked on the first render, only for changes made afterwards.
+    /// `watch_changes_silent` is a hook and should **not** be called inside loops, conditions or callbacks.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     count: i32
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     store.watch_changes_silent(|state| state.count, |prev, next| log_delta(next - prev));
+    ///     
+    ///     html!{}
+    /// }
+    ///
+    /// fn log_delta(delta: i32) { }
+    /// ```
+    #[track_caller]
+    pub fn watch_changes_silent<W: PartialEq + 'static>(
+        &self,
+        watch: impl Fn(&T) -> W + 'static,
+        on_change: impl Fn(&W, &W) + 'static,
+    ) {
+        self.watch_silently("watch_changes_silent", watch, on_change);
+    }
+
+    /// Subscribe the hook being called to a specific store value, invoking `on_change` with its previous and next
+    /// values every time it changes, without re-rendering the component.
+    #[track_caller]
+    fn watch_silently<W: PartialEq + 'static>(
+        &self,
+        hook: &'static str,
+        watch: impl Fn(&T) -> W + 'static,
+        on_change: impl Fn(&W, &W) + 'static,
+    ) {
+        self.state_slot(|state| RefCell::new(watch(state)));
+        self.push_subscription(hook, move |current: Rc<RefCell<W>>, _, next| {
+            apply_change(&current, watch(next), &on_change);
+            current
+        });
+    }
+
+    /// (Hook) Subscribe to the store and return the value mapped, re-rendering the component only once the value
+    /// stopped changing for `duration`.
+    /// Every change to the mapped value restarts the timer, and the latest value is rendered once it elapses.
+    /// The pending timer is cancelled when the component is unmounted.
+    /// `map_debounced` is a hook and should **not** be called inside loops, conditions or callbacks.
+    /// ```rust
+    /// use std::time::Duration;
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     search: String
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let search = store.map_debounced(Duration::from_millis(300), |state| state.search.clone());
+    ///     
+    ///     html!{ { search } }
+    /// }
+    /// ```
+    #[cfg(feature = "timers")]
+    #[track_caller]
+    pub fn map_debounced<M: PartialEq + 'static>(
+        &self,
+        duration: Duration,
+        map: impl Fn(&T) -> M + 'static,
+    ) -> Rc<M> {
+        let slot = self.state_slot(|state| Timed::new(map(state)));
+        let renderer = self.subscriptions.borrow().renderer();
+        self.push_subscription("map_debounced", move |slot: Rc<Timed<M>>, _, next| {
+            slot.debounce(map(next), duration, &renderer);
+            slot
+        });
+        slot.rendered()
+    }
+
+    /// (Hook) Subscribe to a specific store value, re-rendering the component only once the value
+    /// stopped changing for `duration`, as with `map_debounced`.
+    /// `watch_debounced` is a hook and should **not** be called inside loops, conditions or callbacks.
+    /// ```rust
+    /// use std::time::Duration;
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     search: String
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     store.watch_debounced(Duration::from_millis(300), |state| state.search.clone());
+    ///     
+    ///     html!{ { &store.state().search } }
+    /// }
+    /// ```
+    #[cfg(feature = "timers")]
+    #[track_caller]
+    pub fn watch_debounced<W: PartialEq + 'static>(
+        &self,
+        duration: Duration,
+        watch: impl Fn(&T) -> W + 'static,
+    ) {
+        self.map_debounced(duration, watch);
+    }
+
+    /// (Hook) Subscribe to the store and return the value mapped, re-rendering the component at most once per `interval`.
+    /// The `mode` tells whether the first change of each interval is rendered right away, the last change is rendered
+    /// once the interval elapsed, or both. The pending timer is cancelled when the component is unmounted.
+    /// `map_throttled` is a hook and should **not** be called inside loops, conditions or callbacks.
+    /// ```rust
+    /// use std::time::Duration;
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     scroll: i32
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let scroll = store.map_throttled(Duration::from_millis(100), |state| state.scroll, ThrottleMode::Both);
+    ///     
+    ///     html!{ { scroll } }
+    /// }
+    /// ```
+    #[cfg(feature = "timers")]
+    #[track_caller]
+    pub fn map_throttled<M: PartialEq + 'static>(
+        &self,
+        interval: Duration,
+        map: impl Fn(&T) -> M + 'static,
+        mode: ThrottleMode,
+    ) -> Rc<M> {
+        let slot = self.state_slot(|state| Timed::new(map(state)));
+        let renderer = self.subscriptions.borrow().renderer();
+        self.push_subscription("map_throttled", move |slot: Rc<Timed<M>>, _, next| {
+            slot.throttle(map(next), interval, mode, &renderer);
+            slot
+        });
+        slot.rendered()
+    }
+
+    /// (Hook) Subscribe to a specific store value, re-rendering the component at most once per `interval`,
+    /// as with `map_throttled`.
+    /// `watch_throttled` is a hook and should **not** be called inside loops, conditions or callbacks.
+    /// ```rust
+    /// use std::time::Duration;
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     cursor: (i32, i32)
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     store.watch_throttled(Duration::from_millis(16), |state| state.cursor, ThrottleMode::Leading);
+    ///     
+    ///     html!{ format!("{:?}", store.state().cursor) }
+    /// }
+    /// ```
+    #[cfg(feature = "timers")]
+    #[track_caller]
+    pub fn watch_throttled<W: PartialEq + 'static>(
+        &self,
+        interval: Duration,
+        watch: impl Fn(&T) -> W + 'static,
+        mode: ThrottleMode,
+    ) {
+        self.map_throttled(interval, watch, mode);
+    }
+
+    /// (Hook) Subscribe to every change of the store and return the current state.
+    /// Changes are detected by reference, so the state does not need to implement `PartialEq` and is never compared.
+    /// `watch_all` is a hook and should **not** be called inside loops, conditions or callbacks.
+    ///
+    /// Any change to the store state will re-render the component.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     value: i32
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let state = store.watch_all();
+    ///     
+    ///     html!{ { state.value } }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn watch_all(&self) -> Rc<T> {
+        self.state_slot(|_| self.state());
+        self.push_subscription("watch_all", |current: Rc<Rc<T>>, _, next| {
+            if Rc::ptr_eq(&*current, next) {
+                return current;
+            }
+            Rc::new(next.clone())
+        });
+        self.state()
+    }
+
+    /// (Hook) Invoke `on_change` with the value mapped every time it changes, without re-rendering the component.
+    /// `on_change` isn't invoked on the first render, only for changes made afterwards.
+    /// `inspect` is a hook and should **not** be called inside loops, conditions or callbacks.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     page: String
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     store.inspect(|state| state.page.clone(), |page| track_page_view(page));
+    ///     
+    ///     html!{}
+    /// }
+    ///
+    /// fn track_page_view(page: &str) { }
+    /// ```
+    #[track_caller]
+    pub fn inspect<M: PartialEq + 'static>(
+        &self,
+        f: impl Fn(&T) -> M + 'static,
+        on_change: impl Fn(&M) + 'static,
+    ) {
+        self.watch_silently("inspect", f, move |_, next| on_change(next));
+    }
+
+    /// (Hook) Run the `effect` with the value selected whenever it changes, **without** re-rendering the component.
+    /// The `effect` is subscribed to the store directly and is dropped with the component.
+    /// The `select` given on the first render is kept, while the `effect` is replaced on every render.
+    /// `on_change` is a hook and should **not** be called inside loops, conditions or callbacks.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     unread: usize
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     store.on_change(|state| state.unread, |unread| play_notification(*unread));
+    ///
+    ///     html!{}
+    /// }
+    ///
+    /// fn play_notification(unread: usize) { }
+    /// ```
+    #[track_caller]
+    pub fn on_change<W: PartialEq + 'static>(
+        &self,
+        select: impl Fn(&T) -> W + 'static,
+        effect: impl Fn(&W) + 'static,
+    ) {
+        let mut effect = Some(Box::new(effect) as Box<dyn Fn(&W)>);
+        let slot = self.state_slot(|state| OnChange {
+            current: RefCell::new(select(state)),
+            effect: RefCell::new(effect.take()),
+        });
+        self.push_subscription("on_change", |slot: Rc<OnChange<W>>, _, _| slot);
+        if let Some(effect) = effect {
+            // Not the first render, the slot already holds the previous effect.
+            *slot.effect.borrow_mut() = Some(effect);
+            return;
+        }
+        let slot = Rc::downgrade(&slot);
+        self.context.store_ref().subscribe(move |_, next| {
+            let Some(slot) = slot.upgrade() else {
+                return false;
+            };
+            apply_change(&slot.current, select(next), |_, next| {
+                if let Some(effect) = slot.effect.borrow().as_ref() {
+                    effect(next);
+                }
+            });
+            true
+        });
+    }
+
+    /// Read the current state and return the value mapped, **without** subscribing to the store.
+    /// A change to the store state won't re-render the component, which makes `peek` suited for event handlers.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     value: i32
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store::<StoreState>();
+    ///     let onclick = move |_| log(store.peek(|state| state.value));
+    ///     
+    ///     html!{ <button {onclick}>{ "Log" }</button> }
+    /// }
+    ///
+    /// fn log(value: i32) { }
+    /// ```
+    pub fn peek<M>(&self, f: impl FnOnce(&T) -> M) -> M {
+        f(&self.state_ref())
+    }
+
+    /// Give the state held for the hook being called, initializing it on the first render.
+    #[track_caller]
+    fn state_slot<S: 'static>(&self, init: impl FnOnce(&T) -> S) -> Rc<S> {
+        let mut subs = self.subscriptions.borrow_mut();
+        if let Some(state) = subs.current_state() {
+            return state;
+        }
+        let state = Rc::new(init(&self.state_ref()));
+        subs.push_state(state.clone());
+        state
+    }
+
+    /// Register the subscription computing the next state of the hook being called.
+    /// Returning a different `Rc` than the current state will re-render the component.
+    fn push_subscription<S: 'static>(
+        &self,
+        hook: &'static str,
+        subscription: impl Fn(Rc<S>, &Rc<T>, &Rc<T>) -> Rc<S> + 'static,
+    ) {
+        let mut subs = self.subscriptions.borrow_mut();
+        let index = subs.subscriptions.len();
+        let found = match subs.states.get(index) {
+            Some(state) if state.is::<S>() => None,
+            Some(_) => Some(subs.origins[index].type_name),
+            None => Some("no state"),
+        };
+        if let Some(found) = found {
+            panic!(
+                "Store {} expected a state of type {} but found {}.",
+                hook,
+                type_name::<S>(),
+                found
+            );
+        }
+        subs.subscriptions.push(Box::new(move |state, prev, next| {
+            let state = state.downcast::<S>().unwrap_or_else(|_| {
+                panic!(
+                    "Store {} subscription expected a state of type {}.",
+                    hook,
+                    type_name::<S>()
+                )
+            });
+            subscription(state, prev, next)
+        }));
+    }
+}
+
+/// Replace the `current` value with the `next` one when it changed, invoking `on_change` with both values beforehand.
+fn apply_change<W: PartialEq>(current: &RefCell<W>, next: W, on_change: impl FnOnce(&W, &W)) {
+    if *current.borrow() != next {
+        on_change(&current.borrow(), &next);
+        *current.borrow_mut() = next;
+    }
+}
+
+/// State of a `map2` hook, shared by the handles of both stores.
+/// The handle notified returns a new `Combined` sharing the same value to re-render the component.
+struct Combined<M> {
+    value: Rc<RefCell<Rc<M>>>,
+}
+
+/// (Hook) Subscribe to two stores and return the value mapped from both states.
+/// The component re-renders only when the combined value changes, whichever store changed.
+/// `map2` is a hook and should **not** be called inside loops, conditions or callbacks.
+/// ```rust
+/// use yew::prelude::*;
+/// use yewv::*;
+///
+/// struct User {
+///     id: u32
+/// }
+///
+/// struct Document {
+///     owner_id: u32
+/// }
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let (user, document) = use_stores::<(User, Document)>();
+///     let can_edit = map2(&user, &document, |user, document| user.id == document.owner_id);
+///     
+///     html!{ if *can_edit { <button>{ "Edit" }</button> } }
+/// }
+/// ```
+#[track_caller]
+pub fn map2<A: 'static, B: 'static, M: PartialEq + 'static>(
+    a: &UseStoreHandle<A>,
+    b: &UseStoreHandle<B>,
+    map: impl Fn(&A, &B) -> M + 'static,
+) -> Rc<M> {
+    let combined = a.state_slot(|state_a| Combined {
+        value: Rc::new(RefCell::new(Rc::new(map(state_a, &b.state_ref())))),
+    });
+    let value = combined.value.clone();
+    b.state_slot(|_| Combined {
+        value: value.clone(),
+    });
+    let map = Rc::new(map);
+    // Each store is only referenced weakly by the subscription of the other, which would otherwise keep both alive.
+    // Once the other store is dropped, the value is kept as is.
+    a.push_subscription("map2", {
+        let (map, b) = (map.clone(), Rc::downgrade(b.context.store_ref()));
+        move |combined: Rc<Combined<M>>, _, next| match b.upgrade() {
+            Some(b) => combined.update(map(next, &b.state())),
+            None => combined,
+        }
+    });
+    b.push_subscription("map2", {
+        let a = Rc::downgrade(a.context.store_ref());
+        move |combined: Rc<Combined<M>>, _, next| match a.upgrade() {
+            Some(a) => combined.update(map(&a.state(), next)),
+            None => combined,
+        }
+    });
+    let value = value.borrow().clone();
+    value
+}
+
+impl<M: PartialEq> Combined<M> {
+    /// Store the `next` value when it changed, returning a new state to re-render the component.
+    fn update(self: Rc<Self>, next: M) -> Rc<Self> {
+        if **self.value.borrow() == next {
+            return self;
+        }
+        *self.value.borrow_mut() = Rc::new(next);
+        Rc::new(Self {
+            value: self.value.clone(),
+        })
+    }
+}
+
+/// Tuple of selectors, each mapping the store state to a value observed independently.
+pub trait Selectors<T: 'static> {
+    /// Tuple of the values mapped by the selectors.
+    type Output;
+
+    /// Subscribe to the store with every selector, as with `map`.
+    fn select(self, handle: &UseStoreHandle<T>) -> Self::Output;
+}
+
+macro_rules! impl_selectors {
+    ($(($selector:ident, $value:ident)),+) => {
+        impl<T: 'static, $($selector, $value),+> Selectors<T> for ($($selector,)+)
+        where
+            $($selector: Fn(&T) -> $value + 'static, $value: PartialEq + 'static),+
+        {
+            type Output = ($(Rc<$value>,)+);
+
+            #[track_caller]
+            #[allow(non_snake_case)]
+            fn select(self, handle: &UseStoreHandle<T>) -> Self::Output {
+                let ($($selector,)+) = self;
+                ($(handle.map($selector),)+)
+            }
+        }
+    };
+}
+
+impl_selectors!((A, MA));
+impl_selectors!((A, MA), (B, MB));
+impl_selectors!((A, MA), (B, MB), (C, MC));
+impl_selectors!((A, MA), (B, MB), (C, MC), (D, MD));
+
+/// Handle subscribing to the store with several selectors at once.
+pub struct UseStoreSelectorHandle<T: 'static> {
+    pub(crate) handle: UseStoreHandle<T>,
+}
+
+impl<T: 'static> UseStoreSelectorHandle<T> {
+    /// (Hook) Subscribe to the store with every selector of the tuple and return the tuple of values mapped.
+    /// Each value is observed independently, as with `map`, and a change to any of them will re-render the component.
+    /// `select` is a hook and should **not** be called inside loops, conditions or callbacks.
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yewv::*;
+    ///
+    /// struct StoreState {
+    ///     count: i32,
+    ///     name: String,
+    ///     is_admin: bool,
+    /// }
+    ///
+    /// #[function_component]
+    /// fn Test() -> Html {
+    ///     let store = use_store_selector::<StoreState>();
+    ///     let (count, name, is_admin) = store.select((
+    ///         |state: &StoreState| state.count,
+    ///         |state: &StoreState| state.name.clone(),
+    ///         |state: &StoreState| state.is_admin,
+    ///     ));
+    ///     
+    ///     html!{ format!("{} {} {}", count, name, is_admin) }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn select<S: Selectors<T>>(&self, selectors: S) -> S::Output {
+        selectors.select(&self.handle)
+    }
+}
+
+impl<T> Deref for UseStoreSelectorHandle<T> {
+    type Target = UseStoreHandle<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl<T> Debug for UseStoreHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseStoreHandle")
+            .field("context", &self.context)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Deref for UseStoreHandle<T> {
     type Target = Rc<Store<T>>;
 
     fn deref(&self) -> &Self::Target {
-        &self.context.store
+        self.context.store_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn state_slot<S: 'static>(subs: &mut Subscriptions<i32>, init: S) -> Rc<S> {
+        let state = match subs.current_state() {
+            Some(state) => state,
+            None => {
+                let state = Rc::new(init);
+                subs.push_state(state.clone());
+                state
+            }
+        };
+        subs.subscriptions.push(Box::new(|state, _, _| state));
+        state
+    }
+
+    fn render(subs: &mut Subscriptions<i32>, with_first_hook: bool) -> Option<Rc<i32>> {
+        subs.start_render();
+        let first = with_first_hook.then(|| state_slot(subs, 1));
+        state_slot(subs, String::from("second"));
+        first
+    }
+
+    #[test]
+    fn current_state_with_same_call_site_should_give_state() {
+        //Given
+        let mut subs = Subscriptions::default();
+        let mut states = vec![];
+        //When
+        for _ in 0..2 {
+            subs.start_render();
+            states.push(state_slot(&mut subs, 1));
+        }
+        //Then
+        assert!(Rc::ptr_eq(&states[0], &states[1]));
+    }
+
+    #[test]
+    fn current_state_with_different_call_site_should_initialize_state() {
+        //Given
+        let mut subs = Subscriptions::default();
+        let state = state_slot(&mut subs, 1);
+        subs.start_render();
+        //When
+        let other = state_slot(&mut subs, 2);
+        //Then
+        assert!(!Rc::ptr_eq(&state, &other));
+        assert_eq!(*other, 2);
+    }
+
+    #[test]
+    fn current_state_with_different_type_should_initialize_state() {
+        //Given
+        let mut subs = Subscriptions::default();
+        render(&mut subs, true);
+        //When
+        render(&mut subs, false);
+        //Then
+        assert_eq!(subs.states.len(), 1);
+        assert!(subs.states[0].clone().downcast::<String>().is_ok());
+    }
+
+    #[test]
+    fn start_render_with_fewer_hooks_called_should_drop_unused_states() {
+        //Given
+        let mut subs = Subscriptions::default();
+        for _ in 0..3 {
+            subs.start_render();
+            for _ in 0..3 {
+                state_slot(&mut subs, 1);
+            }
+        }
+        subs.start_render();
+        state_slot(&mut subs, 1);
+        //When
+        subs.start_render();
+        //Then
+        assert_eq!(subs.states.len(), 1);
+        assert_eq!(subs.origins.len(), 1);
+    }
+
+    #[test]
+    fn current_state_with_conditional_hook_called_again_should_keep_states_consistent() {
+        //Given
+        let mut subs = Subscriptions::default();
+        render(&mut subs, true);
+        render(&mut subs, false);
+        //When
+        let first = render(&mut subs, true);
+        //Then
+        assert_eq!(first.as_deref(), Some(&1));
+        assert_eq!(subs.states.len(), subs.subscriptions.len());
+    }
+
+    fn keyed_slot(subs: &mut Subscriptions<i32>, key: &'static str) {
+        subs.keyed.insert(
+            SlotKey::new(key),
+            KeyedSlot {
+                state: Rc::new(0),
+                subscription: Some(Box::new(|state, _, _| state)),
+            },
+        );
+    }
+
+    #[test]
+    fn start_render_with_keyed_state_used_on_last_render_should_keep_state() {
+        //Given
+        let mut subs = Subscriptions::default();
+        subs.start_render();
+        keyed_slot(&mut subs, "total");
+        //When
+        subs.start_render();
+        //Then
+        assert!(subs.keyed.contains_key(&SlotKey::new("total")));
+    }
+
+    #[test]
+    fn start_render_with_keyed_state_unused_on_last_render_should_drop_state() {
+        //Given
+        let mut subs = Subscriptions::default();
+        subs.start_render();
+        keyed_slot(&mut subs, "total");
+        subs.start_render();
+        keyed_slot(&mut subs, "count");
+        //When
+        subs.start_render();
+        //Then
+        assert!(!subs.keyed.contains_key(&SlotKey::new("total")));
+        assert!(subs.keyed.contains_key(&SlotKey::new("count")));
+    }
+
+    #[test]
+    fn map_labeled_should_label_state_of_hook() {
+        //Given
+        let handle = UseStoreHandle {
+            context: StoreContext::new(0),
+            subscriptions: Rc::new(RefCell::new(Subscriptions::default())),
+        };
+        handle.map(|state| *state);
+        //When
+        handle.map_labeled("double", |state| state * 2);
+        //Then
+        let subs = handle.subscriptions.borrow();
+        assert_eq!((subs.label(0), subs.label(1)), (None, Some("double")));
+    }
+
+    #[test]
+    fn apply_change_with_changed_value_should_notify_and_replace_value() {
+        //Given
+        let current = RefCell::new(1);
+        let mut changes = vec![];
+        //When
+        apply_change(&current, 2, |prev, next| changes.push((*prev, *next)));
+        apply_change(&current, 2, |prev, next| changes.push((*prev, *next)));
+        //Then
+        assert_eq!(changes, &[(1, 2)]);
+        assert_eq!(*current.borrow(), 2);
+    }
+
+    #[test]
+    fn push_subscription_with_state_of_other_type_should_panic_with_both_type_names() {
+        //Given
+        let handle = UseStoreHandle {
+            context: StoreContext::new(0),
+            subscriptions: Rc::new(RefCell::new(Subscriptions::default())),
+        };
+        handle.state_slot(|_| 1u8);
+        //When
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            handle.push_subscription("map", |state: Rc<String>, _, _| state)
+        }));
+        //Then
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert_eq!(
+            message,
+            "Store map expected a state of type alloc::string::String but found u8."
+        );
     }
 }
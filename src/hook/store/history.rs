@@ -0,0 +1,69 @@
+use std::{collections::VecDeque, rc::Rc};
+
+/// Kind of state transition, telling how the history should be updated.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Transition {
+    Commit,
+    Undo,
+    Redo,
+}
+
+/// Bounded record of the states committed to a store.
+pub(crate) struct History<T> {
+    capacity: usize,
+    past: VecDeque<Rc<T>>,
+    future: Vec<Rc<T>>,
+}
+
+impl<T> History<T> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            past: VecDeque::with_capacity(capacity),
+            future: vec![],
+        }
+    }
+
+    /// State to install on undo.
+    pub(crate) fn previous(&self) -> Option<Rc<T>> {
+        self.past.back().cloned()
+    }
+
+    /// Recorded previous states, from the oldest to the most recent.
+    pub(crate) fn past(&self) -> Vec<Rc<T>> {
+        self.past.iter().cloned().collect()
+    }
+
+    /// State to install on redo.
+    pub(crate) fn next(&self) -> Option<Rc<T>> {
+        self.future.last().cloned()
+    }
+
+    /// Record the `current` state being replaced by the given `transition`.
+    pub(crate) fn record(&mut self, current: Rc<T>, transition: Transition) {
+        match transition {
+            Transition::Commit => {
+                self.push_past(current);
+                self.future.clear();
+            }
+            Transition::Undo => {
+                self.past.pop_back();
+                self.future.push(current);
+            }
+            Transition::Redo => {
+                self.future.pop();
+                self.push_past(current);
+            }
+        }
+    }
+
+    fn push_past(&mut self, state: Rc<T>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.past.len() == self.capacity {
+            self.past.pop_front();
+        }
+        self.past.push_back(state);
+    }
+}
@@ -0,0 +1,125 @@
+use super::{Store, StoreContext};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Error raised when a store state can't be converted from or to JSON.
+pub use serde_json::Error as JsonError;
+
+impl<T: Serialize> Store<T> {
+    /// Serialize the current store state to JSON.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(vec![1, 2]);
+    /// assert_eq!(store.to_json().unwrap(), "[1,2]");
+    /// ```
+    pub fn to_json(&self) -> Result<String, JsonError> {
+        serde_json::to_string(&**self.state_ref())
+    }
+}
+
+impl<T: DeserializeOwned> Store<T> {
+    /// Deserialize the given JSON and set it as the store next state.
+    /// Subscribers are notified like with any other change.
+    /// On error, the store state is left untouched.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(vec![1, 2]);
+    /// store.load_json("[3]").unwrap();
+    /// assert_eq!(*store.state(), vec![3]);
+    /// ```
+    pub fn load_json(&self, json: &str) -> Result<(), JsonError> {
+        self.set_state(serde_json::from_str(json)?);
+        Ok(())
+    }
+}
+
+impl<T: DeserializeOwned> StoreContext<T> {
+    /// Creates a new `StoreContext` with the initial state deserialized from the given JSON.
+    /// ```rust
+    /// use yewv::StoreContext;
+    ///
+    /// let store = StoreContext::<Vec<i32>>::new_from_json("[1, 2]").unwrap();
+    /// assert_eq!(*store.state(), vec![1, 2]);
+    /// ```
+    pub fn new_from_json(json: &str) -> Result<Self, JsonError> {
+        Ok(Self::new(serde_json::from_str(json)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::{cell::RefCell, rc::Rc};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct TestState {
+        name: String,
+        count: i32,
+    }
+
+    #[test]
+    fn load_json_with_serialized_state_should_restore_state() {
+        //Given
+        let store = Store::new(TestState {
+            name: "test".to_string(),
+            count: 1,
+        });
+        let json = store.to_json().unwrap();
+        store.set_state(TestState {
+            name: "other".to_string(),
+            count: 2,
+        });
+        //When
+        store.load_json(&json).unwrap();
+        //Then
+        assert_eq!(
+            *store.state(),
+            TestState {
+                name: "test".to_string(),
+                count: 1
+            }
+        );
+    }
+
+    #[test]
+    fn load_json_with_valid_json_should_notify() {
+        //Given
+        let store = Store::new(vec![1]);
+        let notified_values = Rc::new(RefCell::new(vec![]));
+        store.subscribe({
+            let notified_values = notified_values.clone();
+            move |prev: &Vec<i32>, next: &Vec<i32>| {
+                notified_values
+                    .borrow_mut()
+                    .push((prev.clone(), next.clone()));
+                true
+            }
+        });
+        //When
+        store.load_json("[2]").unwrap();
+        //Then
+        assert_eq!(*notified_values.borrow(), &[(vec![1], vec![2])]);
+    }
+
+    #[test]
+    fn load_json_with_invalid_json_should_keep_state() {
+        //Given
+        let store = Store::new(vec![1]);
+        //When
+        let result = store.load_json("{");
+        //Then
+        assert!(result.is_err());
+        assert_eq!(*store.state(), vec![1]);
+    }
+
+    #[test]
+    fn new_from_json_with_invalid_json_should_fail() {
+        //Given
+        //When
+        let result = StoreContext::<Vec<i32>>::new_from_json("{");
+        //Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    any::{Any, TypeId},
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+type KeyedSubscription<T> = Box<dyn Fn(Rc<dyn Any>, &Rc<T>, &Rc<T>) -> Rc<dyn Any>>;
+
+/// Key given to a keyed hook, of any hashable type.
+trait DynKey {
+    fn as_any(&self) -> &dyn Any;
+    fn dyn_eq(&self, other: &dyn DynKey) -> bool;
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+    fn dyn_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl<K: Hash + Eq + Debug + 'static> DynKey for K {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn DynKey) -> bool {
+        other.as_any().downcast_ref::<K>() == Some(self)
+    }
+
+    fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+        TypeId::of::<K>().hash(&mut state);
+        self.hash(&mut state);
+    }
+
+    fn dyn_fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt(f)
+    }
+}
+
+/// Key identifying the state of a keyed hook, regardless of the order in which hooks are called.
+pub(crate) struct SlotKey(Box<dyn DynKey>);
+
+impl SlotKey {
+    pub(crate) fn new<K: Hash + Eq + Debug + 'static>(key: K) -> Self {
+        Self(Box::new(key))
+    }
+}
+
+impl PartialEq for SlotKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.dyn_eq(&*other.0)
+    }
+}
+
+impl Eq for SlotKey {}
+
+impl Hash for SlotKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.dyn_hash(state);
+    }
+}
+
+impl Debug for SlotKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.dyn_fmt(f)
+    }
+}
+
+/// State and subscription of a keyed hook.
+pub(crate) struct KeyedSlot<T> {
+    pub(crate) state: Rc<dyn Any>,
+    pub(crate) subscription: Option<KeyedSubscription<T>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn eq_with_same_type_and_value_should_be_equal() {
+        //Given
+        let key = SlotKey::new("total");
+        //When
+        let other = SlotKey::new("total");
+        //Then
+        assert_eq!(key, other);
+    }
+
+    #[test]
+    fn eq_with_different_types_should_not_be_equal() {
+        //Given
+        let key = SlotKey::new(1u32);
+        //When
+        let other = SlotKey::new(1u64);
+        //Then
+        assert_ne!(key, other);
+    }
+
+    #[test]
+    fn hash_map_with_keys_of_several_types_should_find_each_key() {
+        //Given
+        let mut map = HashMap::new();
+        map.insert(SlotKey::new(1u32), "u32");
+        map.insert(SlotKey::new("1"), "str");
+        //When
+        let found = (
+            map.get(&SlotKey::new(1u32)),
+            map.get(&SlotKey::new("1")),
+            map.get(&SlotKey::new(1i32)),
+        );
+        //Then
+        assert_eq!(found, (Some(&"u32"), Some(&"str"), None));
+    }
+
+    #[test]
+    fn debug_should_format_key() {
+        //Given
+        let key = SlotKey::new("total");
+        //When
+        let debug = format!("{:?}", key);
+        //Then
+        assert_eq!(debug, "\"total\"");
+    }
+}
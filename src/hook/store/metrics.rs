@@ -0,0 +1,128 @@
+#[cfg(feature = "metrics")]
+use super::Store;
+#[cfg(feature = "metrics")]
+use std::{cell::Cell, rc::Rc};
+
+/// Counters describing the work done by a store and its hooks to notify changes.
+#[cfg(feature = "metrics")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StoreMetrics {
+    /// Number of times a subscription was notified of a change.
+    pub notifications: usize,
+    /// Number of values compared by hooks to decide whether to re-render.
+    pub comparator_runs: usize,
+    /// Number of renders requested by hooks.
+    pub renders_requested: usize,
+}
+
+/// Records the metrics of a store, doing nothing unless the `metrics` feature is enabled.
+/// Notifications aren't recorded here, they are counted by the store for `stats`.
+#[derive(Clone, Default)]
+pub(crate) struct MetricsRecorder {
+    #[cfg(feature = "metrics")]
+    counters: Rc<Cell<StoreMetrics>>,
+    /// Store notifications count when the metrics were last reset.
+    #[cfg(feature = "metrics")]
+    notifications_reset: Rc<Cell<usize>>,
+}
+
+impl MetricsRecorder {
+    pub(crate) fn compared(&self) {
+        #[cfg(feature = "metrics")]
+        self.update(|metrics| metrics.comparator_runs += 1);
+    }
+
+    pub(crate) fn render_requested(&self) {
+        #[cfg(feature = "metrics")]
+        self.update(|metrics| metrics.renders_requested += 1);
+    }
+
+    #[cfg(feature = "metrics")]
+    fn update(&self, f: impl FnOnce(&mut StoreMetrics)) {
+        let mut metrics = self.counters.get();
+        f(&mut metrics);
+        self.counters.set(metrics);
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl<T> Store<T> {
+    /// Give the metrics recorded since the store creation or the last call to `reset_metrics`.
+    /// Notifications are the ones counted by `stats`, since the metrics were last reset.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// store.subscribe(|_, _| true);
+    /// store.set_state(1);
+    /// assert_eq!(store.metrics().notifications, 1);
+    /// ```
+    pub fn metrics(&self) -> StoreMetrics {
+        StoreMetrics {
+            notifications: self.stats().notifications - self.metrics.notifications_reset.get(),
+            ..self.metrics.counters.get()
+        }
+    }
+
+    /// Reset every metric to zero, to measure the work done by a given sequence of changes.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// store.subscribe(|_, _| true);
+    /// store.set_state(1);
+    /// store.reset_metrics();
+    /// assert_eq!(store.metrics().notifications, 0);
+    /// ```
+    pub fn reset_metrics(&self) {
+        self.metrics.counters.set(StoreMetrics::default());
+        self.metrics
+            .notifications_reset
+            .set(self.stats().notifications);
+    }
+}
+
+#[cfg(all(test, feature = "metrics"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metrics_with_changes_notified_should_count_notifications() {
+        //Given
+        let store = Store::new(0);
+        store.subscribe(|_, _| true);
+        store.subscribe(|_, _| true);
+        //When
+        store.set_state(1);
+        //Then
+        assert_eq!(store.metrics().notifications, 2);
+    }
+
+    #[test]
+    fn reset_metrics_with_metrics_recorded_should_reset_counters() {
+        //Given
+        let store = Store::new(0);
+        store.subscribe(|_, _| true);
+        store.set_state(1);
+        store.metrics.compared();
+        store.metrics.render_requested();
+        //When
+        store.reset_metrics();
+        //Then
+        assert_eq!(store.metrics(), StoreMetrics::default());
+    }
+
+    #[test]
+    fn metrics_after_reset_should_count_notifications_of_stats_since_reset() {
+        //Given
+        let store = Store::new(0);
+        store.subscribe(|_, _| true);
+        store.set_state(1);
+        store.reset_metrics();
+        //When
+        store.set_state(2);
+        //Then
+        assert_eq!(store.metrics().notifications, 1);
+        assert_eq!(store.stats().notifications, 2);
+    }
+}
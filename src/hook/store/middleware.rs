@@ -0,0 +1,69 @@
+/// Outcome of a middleware `before` hook.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Decision {
+    /// Let the state transition happen.
+    Continue,
+    /// Abort the state transition. The store state is left untouched and subscribers are not notified.
+    Cancel,
+}
+
+/// Hooks running around every state transition of a store.
+/// ```rust
+/// use yewv::{Decision, Middleware, Store};
+///
+/// struct PositiveOnly;
+///
+/// impl Middleware<i32> for PositiveOnly {
+///     fn before(&self, _prev: &i32, next: &i32) -> Decision {
+///         if *next < 0 {
+///             return Decision::Cancel;
+///         }
+///         Decision::Continue
+///     }
+/// }
+///
+/// let store = Store::new(0);
+/// store.add_middleware(PositiveOnly);
+/// store.set_state(-1);
+/// assert_eq!(*store.state(), 0);
+/// ```
+pub trait Middleware<T> {
+    /// Called before `next` replaces `prev` as the store state.
+    /// Returning `Decision::Cancel` aborts the transition and skips the remaining middlewares.
+    fn before(&self, _prev: &T, _next: &T) -> Decision {
+        Decision::Continue
+    }
+
+    /// Called right before subscribers are notified of the transition from `prev` to `next`,
+    /// including notifications forced with `force_notify`.
+    fn notify(&self, _prev: &T, _next: &T) {}
+
+    /// Called once subscribers have been notified of the transition from `prev` to `next`.
+    fn after(&self, _prev: &T, _next: &T) {}
+}
+
+/// Closures are middlewares invoked on every notification, before subscribers.
+/// As opposed to a subscription, a closure middleware is never dropped.
+/// ```rust
+/// use yewv::Store;
+///
+/// let store = Store::new(0);
+/// store.add_middleware(|prev: &i32, next: &i32| println!("{} -> {}", prev, next));
+/// store.set_state(1);
+/// ```
+impl<T, F: Fn(&T, &T)> Middleware<T> for F {
+    fn notify(&self, prev: &T, next: &T) {
+        self(prev, next)
+    }
+}
+
+/// Middleware logging every state transition with the `log` crate.
+#[cfg(feature = "logger")]
+pub struct LoggerMiddleware;
+
+#[cfg(feature = "logger")]
+impl<T: std::fmt::Debug> Middleware<T> for LoggerMiddleware {
+    fn after(&self, prev: &T, next: &T) {
+        log::info!("{}: {:?} -> {:?}", std::any::type_name::<T>(), prev, next);
+    }
+}
@@ -1,12 +1,71 @@
+mod async_value;
+mod bridge;
 mod context;
+mod differ;
+mod fallback;
 mod handle;
+mod history;
+#[cfg(feature = "serde")]
+mod json;
+mod keyed;
+mod metrics;
+mod middleware;
+#[cfg(feature = "persist")]
+mod persist;
+mod reducer;
+mod selection;
+mod selector;
 mod store;
+mod stores;
+#[cfg(feature = "async")]
+mod stream;
+mod sub_store;
+#[cfg(feature = "sync")]
+mod sync;
+#[cfg(feature = "threadsafe")]
+mod sync_store;
+#[cfg(feature = "timers")]
+mod timed;
+mod validated;
+mod value_eq;
+mod vec_diff;
+#[cfg(feature = "async")]
+mod wait;
 
+use super::message::not_registered;
+pub use async_value::*;
+pub use bridge::StoreBridge;
 pub use context::*;
+pub use differ::*;
+use fallback::fallback_store;
 pub use handle::*;
-use std::{cell::RefCell, rc::Rc};
+#[cfg(feature = "serde")]
+pub use json::JsonError;
+#[cfg(feature = "metrics")]
+pub use metrics::StoreMetrics;
+pub use middleware::*;
+pub use reducer::*;
+pub use selection::*;
+pub use selector::*;
+use std::{
+    any::type_name,
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
 pub use store::*;
-use yew::{hook, use_context, use_force_update, use_state};
+pub use stores::{use_stores, Stores};
+pub use sub_store::*;
+#[cfg(feature = "threadsafe")]
+pub use sync_store::SyncStore;
+#[cfg(feature = "timers")]
+pub use timed::ThrottleMode;
+pub use validated::*;
+pub use value_eq::ValueEqStoreContext;
+pub use vec_diff::KeyedDiff;
+use yew::{
+    functional::{TearDown, UseForceUpdateHandle},
+    hook, use_context, use_effect_with_deps, use_force_update, use_state,
+};
 
 /// Obtain a store context for the given state `T`.
 /// ```rust
@@ -25,73 +84,426 @@ use yew::{hook, use_context, use_force_update, use_state};
 ///     html!{ { value } }
 /// }
 /// ```
+///
+/// `use_store` must be called while rendering a function component, or from another hook.
+/// Anywhere else, such as in a struct component, it doesn't give a handle and fails to compile.
+/// Struct components can subscribe to the store of the `StoreContext` instead.
+/// ```rust,compile_fail
+/// use yew::prelude::*;
+/// use yewv::use_store;
+///
+/// struct StoreState {
+///     value: i32
+/// }
+///
+/// struct Test;
+///
+/// impl Component for Test {
+///     type Message = ();
+///     type Properties = ();
+///
+///     fn create(_ctx: &Context<Self>) -> Self {
+///         Self
+///     }
+///
+///     fn view(&self, _ctx: &Context<Self>) -> Html {
+///         let store = use_store::<StoreState>();
+///         let value = store.map_ref(|state| &state.value);
+///
+///         html!{ { value } }
+///     }
+/// }
+/// ```
 #[hook]
 pub fn use_store<T: 'static>() -> UseStoreHandle<T> {
-    let context = use_context::<StoreContext<T>>().expect("Store context not registered");
+    use_store_opt::<T>().unwrap_or_else(|| {
+        panic!(
+            "{}",
+            not_registered("use_store", "StoreContext", type_name::<T>())
+        )
+    })
+}
+
+/// Obtain a store context for the given state `T`, to subscribe with several selectors at once.
+/// ```rust
+/// use yew::prelude::*;
+/// use yewv::use_store_selector;
+///
+/// struct StoreState {
+///     count: i32,
+///     name: String,
+/// }
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let store = use_store_selector::<StoreState>();
+///     let (count, name) = store.select((
+///         |state: &StoreState| state.count,
+///         |state: &StoreState| state.name.clone(),
+///     ));
+///
+///     html!{ format!("{} {}", count, name) }
+/// }
+/// ```
+#[hook]
+pub fn use_store_selector<T: 'static>() -> UseStoreSelectorHandle<T> {
+    UseStoreSelectorHandle {
+        handle: use_store::<T>(),
+    }
+}
+
+/// Obtain the current state of the store `T`, re-rendering the component on every change.
+/// Changes are detected by reference, so `T` does not need to implement `PartialEq`.
+/// This is equivalent to calling `watch_all` on the handle given by `use_store`.
+/// ```rust
+/// use yew::prelude::*;
+/// use yewv::use_store_value;
+///
+/// struct StoreState {
+///     value: i32
+/// }
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let state = use_store_value::<StoreState>();
+///     
+///     html!{ { state.value } }
+/// }
+/// ```
+#[hook]
+pub fn use_store_value<T: 'static>() -> Rc<T> {
+    use_store::<T>().watch_all()
+}
+
+/// Select a value from the store `T`, re-rendering the component when it changes.
+/// This is equivalent to calling `map` on the handle given by `use_store`.
+///
+/// Each call subscribes to the store on its own, as Yew gives hooks no way to share state within a component.
+/// To select several values, prefer a single `use_store` with `map` or `use_store_selector`, which share one subscription.
+/// ```rust
+/// use yew::prelude::*;
+/// use yewv::use_selector;
+///
+/// struct StoreState {
+///     count: i32,
+///     name: String,
+/// }
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let count = use_selector(|state: &StoreState| state.count);
+///     let name = use_selector(|state: &StoreState| state.name.clone());
+///     
+///     html!{ format!("{} {}", count, name) }
+/// }
+/// ```
+#[hook]
+pub fn use_selector<T, M, S>(selector: S) -> Rc<M>
+where
+    T: 'static,
+    M: PartialEq + 'static,
+    S: Fn(&T) -> M + 'static,
+{
+    use_store::<T>().map(selector)
+}
+
+/// Select a value from the store `T`, using `compare` to compare selected values, as with `map_with`.
+/// `compare` receives the previous and next values, in that order, and returns `true` when they are equal.
+/// ```rust
+/// use yew::prelude::*;
+/// use yewv::use_selector_eq;
+///
+/// struct StoreState {
+///     ratio: f64
+/// }
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let ratio = use_selector_eq(|state: &StoreState| state.ratio, |prev, next| (prev - next).abs() < 0.01);
+///     
+///     html!{ { ratio } }
+/// }
+/// ```
+#[hook]
+pub fn use_selector_eq<T, M, S, C>(selector: S, compare: C) -> Rc<M>
+where
+    T: 'static,
+    M: 'static,
+    S: Fn(&T) -> M + 'static,
+    C: Fn(&M, &M) -> bool + 'static,
+{
+    use_store::<T>().map_with(selector, compare)
+}
+
+/// Run the `effect` with the value selected from the store `T` on mount, then again whenever it changes.
+/// The teardown returned by the previous run of the `effect` is called before each new run, and on unmount.
+///
+/// To run an effect on changes only, without a teardown, use `UseStoreHandle::on_change` instead.
+/// ```rust
+/// use yew::prelude::*;
+/// use yewv::use_store_effect;
+///
+/// struct StoreState {
+///     title: String
+/// }
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     use_store_effect(|state: &StoreState| state.title.clone(), |title| {
+///         set_document_title(title);
+///         || set_document_title("")
+///     });
+///     
+///     html!{}
+/// }
+///
+/// fn set_document_title(title: &str) { }
+/// ```
+#[hook]
+pub fn use_store_effect<T, W, S, E, D>(select: S, effect: E)
+where
+    T: 'static,
+    W: PartialEq + 'static,
+    S: Fn(&T) -> W + 'static,
+    E: FnOnce(&W) -> D + 'static,
+    D: TearDown,
+{
+    let store = use_store::<T>();
+    let value = store.map(select);
+    use_effect_with_deps(move |value: &Rc<W>| effect(value), value);
+}
+
+/// Obtain a store context for the given state `T`, if registered.
+/// As opposed to `use_store`, `use_store_opt` returns `None` instead of panicking when no
+/// store context is registered in a parent component.
+/// ```rust
+/// use yew::prelude::*;
+/// use yewv::use_store_opt;
+///
+/// struct StoreState {
+///     value: i32
+/// }
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     match use_store_opt::<StoreState>() {
+///         Some(store) => html!{ { store.map_ref(|state| &state.value) } },
+///         None => html!{ "No store registered" },
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_store_opt<T: 'static>() -> Option<UseStoreHandle<T>> {
+    use_marked_store_opt::<T, ()>()
+}
+
+/// Obtain a store context for the given state `T`, falling back to a store created with `init` when none is registered.
+/// The registered store, when present, always wins and `init` is not called.
+///
+/// The fallback store is created once per state type, by the first component calling the hook, and is then
+/// shared by every component calling it without a registered store, so they stay in sync. It lives for as long
+/// as the application thread, even once no component uses it anymore.
+/// ```rust
+/// use yew::prelude::*;
+/// use yewv::use_store_or_init;
+///
+/// struct CounterState {
+///     count: i32
+/// }
+///
+/// #[function_component]
+/// fn Counter() -> Html {
+///     let store = use_store_or_init(|| CounterState { count: 0 });
+///     let count = store.map(|state| state.count);
+///     
+///     html!{ { count } }
+/// }
+/// ```
+#[hook]
+pub fn use_store_or_init<T, I>(init: I) -> UseStoreHandle<T>
+where
+    T: 'static,
+    I: FnOnce() -> T,
+{
+    let context = use_context::<StoreContext<T>>();
+    let renderer = use_force_update();
+    let hook = use_state(StoreSlot::<T>::default);
+    let context = context.unwrap_or_else(|| fallback_store(init));
+    hook.bind(Some(context), &renderer)
+        .expect("Store context should be bound once given.")
+}
+
+/// Obtain a store context for the given state `T`, registered as `StoreContext<T, K>`.
+/// Markers let several stores of the same state type be registered side by side.
+/// ```rust
+/// use yew::prelude::*;
+/// use yewv::*;
+///
+/// struct Sidebar;
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let store = use_marked_store::<Vec<String>, Sidebar>();
+///     let count = store.map(|items| items.len());
+///     
+///     html!{ { count } }
+/// }
+/// ```
+#[hook]
+pub fn use_marked_store<T: 'static, K: 'static>() -> UseStoreHandle<T> {
+    use_marked_store_opt::<T, K>().unwrap_or_else(|| {
+        let params = format!("{}, {}", type_name::<T>(), type_name::<K>());
+        panic!(
+            "{}",
+            not_registered("use_marked_store", "StoreContext", &params)
+        )
+    })
+}
+
+/// Obtain a store context for the given state `T`, registered as `StoreContext<T, K>`, if registered.
+/// As opposed to `use_marked_store`, `use_marked_store_opt` returns `None` instead of panicking when no
+/// store context is registered in a parent component.
+#[hook]
+pub fn use_marked_store_opt<T: 'static, K: 'static>() -> Option<UseStoreHandle<T>> {
+    let context = use_context::<StoreContext<T, K>>();
     let renderer = use_force_update();
     // use_state is use because it is the most efficient hook to hold a state in Yew 0.20.
     // Another way to be ~5% more efficient would be to implement our own hook unsafely.
     // However, the difference is not significant enought to justify the use of unsafe.
-    let subscriptions = use_state({
-        let store = context.store.clone();
-        move || {
-            let is_active = Rc::new(RefCell::new(true));
-            let watch = WatchState(is_active.clone());
-            let subs = Rc::new(RefCell::new(Subscriptions::<T> {
-                states: vec![],
-                subscriptions: vec![],
-                ref_subscriptions: vec![],
+    let hook = use_state(StoreSlot::<T>::default);
+    hook.bind(context, &renderer)
+}
+
+/// Subscriptions of a store hook, along with the store they are bound to.
+struct StoreSlot<T: 'static> {
+    subscriptions: Rc<RefCell<Subscriptions<T>>>,
+    binding: RefCell<Option<Binding<T>>>,
+}
+
+impl<T: 'static> StoreSlot<T> {
+    /// Give a handle to the store of the `context`, subscribing the component to it.
+    /// The slot is bound again when the provider appeared, disappeared or was replaced since the last render.
+    fn bind<K: 'static>(
+        &self,
+        context: Option<StoreContext<T, K>>,
+        renderer: &UseForceUpdateHandle,
+    ) -> Option<UseStoreHandle<T>> {
+        let store = context.as_ref().map(|context| context.store_ref());
+        let mut binding = self.binding.borrow_mut();
+        let is_bound = match (binding.as_ref(), store) {
+            (Some(binding), Some(store)) => binding.store.as_ptr() == Rc::as_ptr(store),
+            (None, None) => true,
+            _ => false,
+        };
+        if !is_bound {
+            let mut subscriptions = Subscriptions::default();
+            subscriptions.renderer = Some(Rc::new({
+                let renderer = renderer.clone();
+                move || renderer.force_update()
             }));
-            store.subscribe({
-                let subs = subs.clone();
-                move |prev, next| {
-                    if !*is_active.borrow() {
-                        return false;
-                    }
-                    let mut subs = subs.borrow_mut();
-                    if !subs.subscriptions.is_empty() {
-                        let mut require_render = false;
-                        let mut next_states = std::mem::take(&mut subs.states);
-                        for (i, sub) in subs.subscriptions.iter().enumerate() {
-                            let state = next_states
-                                .get_mut(i)
-                                .expect("Store subscription has no corresponding state.");
-                            let next_state = sub(state.clone(), &next);
-                            require_render |= !Rc::ptr_eq(&state, &next_state);
-                            *state = next_state
-                        }
-                        subs.states = next_states;
-                        if require_render {
-                            renderer.force_update();
-                            return true;
-                        }
-                    }
-                    for sub in subs.ref_subscriptions.iter() {
-                        if sub(prev, next) {
-                            renderer.force_update();
-                            return true;
-                        }
-                    }
-                    true
-                }
+            *self.subscriptions.borrow_mut() = subscriptions;
+            *binding = store.map(|store| Binding {
+                store: Rc::downgrade(store),
+                _watch: subscribe(store, self.subscriptions.clone(), renderer.clone()),
             });
-            (subs, watch)
         }
-    })
-    .0
-    .clone();
-    {
-        let mut subs = subscriptions.borrow_mut();
-        subs.subscriptions.clear();
-        subs.ref_subscriptions.clear();
+        let context = context?;
+        self.subscriptions.borrow_mut().start_render();
+
+        Some(UseStoreHandle {
+            context: context.marked(),
+            subscriptions: self.subscriptions.clone(),
+        })
     }
+}
 
-    UseStoreHandle {
-        context,
-        subscriptions,
+impl<T: 'static> Default for StoreSlot<T> {
+    fn default() -> Self {
+        Self {
+            subscriptions: Rc::new(RefCell::new(Subscriptions::default())),
+            binding: RefCell::new(None),
+        }
     }
 }
 
+/// Store the hook is subscribed to.
+struct Binding<T> {
+    store: Weak<Store<T>>,
+    _watch: WatchState,
+}
+
+/// Subscribe the component to the `store`, re-rendering it when its subscriptions require it.
+/// The subscription is dropped on the next change once the returned state is dropped.
+fn subscribe<T: 'static>(
+    store: &Store<T>,
+    subs: Rc<RefCell<Subscriptions<T>>>,
+    renderer: UseForceUpdateHandle,
+) -> WatchState {
+    let is_active = Rc::new(RefCell::new(true));
+    let watch = WatchState(is_active.clone());
+    let metrics = store.metrics.clone();
+    store.subscribe_forced(move |forced, prev, next| {
+        if !*is_active.borrow() {
+            return false;
+        }
+        let mut subs = subs.borrow_mut();
+        if !subs.subscriptions.is_empty() || !subs.keyed.is_empty() {
+            let mut require_render = false;
+            let subs = &mut *subs;
+            for (i, sub) in subs.subscriptions.iter().enumerate() {
+                let state = subs
+                    .states
+                    .get_mut(i)
+                    .expect("Store subscription has no corresponding state.");
+                let next_state = sub(state.clone(), prev, next);
+                metrics.compared();
+                let changed = !Rc::ptr_eq(state, &next_state);
+                *state = next_state;
+                #[cfg(all(debug_assertions, feature = "logger"))]
+                if let Some(label) = subs.label(i).filter(|_| changed) {
+                    log::debug!(
+                        "{}: subscription '{}' requested a render.",
+                        type_name::<T>(),
+                        label
+                    );
+                }
+                require_render |= changed;
+            }
+            for slot in subs.keyed.values_mut() {
+                if let Some(sub) = &slot.subscription {
+                    let next_state = sub(slot.state.clone(), prev, next);
+                    metrics.compared();
+                    require_render |= !Rc::ptr_eq(&slot.state, &next_state);
+                    slot.state = next_state;
+                }
+            }
+            if require_render {
+                metrics.render_requested();
+                renderer.force_update();
+                return true;
+            }
+        }
+        let has_subscriptions = !subs.subscriptions.is_empty()
+            || !subs.ref_subscriptions.is_empty()
+            || !subs.keyed.is_empty();
+        if forced && has_subscriptions {
+            metrics.render_requested();
+            renderer.force_update();
+            return true;
+        }
+        for sub in subs.ref_subscriptions.iter() {
+            metrics.compared();
+            if sub(prev, next) {
+                metrics.render_requested();
+                renderer.force_update();
+                return true;
+            }
+        }
+        true
+    });
+    watch
+}
+
 struct WatchState(Rc<RefCell<bool>>);
 impl Drop for WatchState {
     fn drop(&mut self) {
@@ -0,0 +1,72 @@
+use super::StoreContext;
+use serde::{de::DeserializeOwned, Serialize};
+use web_sys::{console, Storage};
+
+fn local_storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> StoreContext<T> {
+    /// Creates a new `StoreContext` persisted in `localStorage` under the given `key`.
+    /// The initial state is read from `localStorage`, falling back to `default` when missing or invalid.
+    /// Every state change is then written back to `localStorage`.
+    ///
+    /// When `localStorage` is unavailable (e.g. private browsing), the store is kept in memory only.
+    /// Failing writes, such as when the storage quota is exceeded, are logged to the console and skipped.
+    /// ```rust,no_run
+    /// use serde::{Deserialize, Serialize};
+    /// use yewv::StoreContext;
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct AppState {
+    ///     count: i32,
+    /// }
+    ///
+    /// let store = StoreContext::new_persisted("app", AppState { count: 0 });
+    /// ```
+    pub fn new_persisted(key: &str, default: T) -> Self {
+        let storage = local_storage();
+        let initial_state = storage
+            .as_ref()
+            .and_then(|storage| storage.get_item(key).ok().flatten())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or(default);
+        let context = Self::new(initial_state);
+        if let Some(storage) = storage {
+            let key = key.to_string();
+            context.subscribe(move |_, next| {
+                let result = serde_json::to_string(next)
+                    .map_err(|e| e.to_string().into())
+                    .and_then(|json| storage.set_item(&key, &json));
+                if let Err(e) = result {
+                    console::error_2(&format!("Failed to persist store '{}':", key).into(), &e);
+                }
+                true
+            });
+        }
+        context
+    }
+
+    /// Creates a new `StoreContext` persisted in `localStorage` under the given `key`, as with `new_persisted`.
+    /// ```rust,no_run
+    /// use yewv::StoreContext;
+    ///
+    /// let store = StoreContext::persistent("count", 0);
+    /// ```
+    pub fn persistent(key: &str, initial: T) -> Self {
+        Self::new_persisted(key, initial)
+    }
+
+    /// Remove the state persisted in `localStorage` under the given `key`.
+    /// Stores already persisted under `key` keep their state and write it back on their next change.
+    /// ```rust,no_run
+    /// use yewv::StoreContext;
+    ///
+    /// StoreContext::<i32>::clear_persisted("app");
+    /// ```
+    pub fn clear_persisted(key: &str) {
+        if let Some(storage) = local_storage() {
+            let _ = storage.remove_item(key);
+        }
+    }
+}
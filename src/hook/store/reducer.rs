@@ -0,0 +1,80 @@
+use super::StoreContext;
+use std::{ops::Deref, rc::Rc};
+
+type Reducer<T, A> = Rc<dyn Fn(&T, A) -> T>;
+
+/// Context holding a reference to a store whose changes are expressed as actions of type `A`.
+/// Child components keep using `use_store::<T>()` with the underlying `StoreContext<T>`.
+pub struct ReducerStoreContext<T, A> {
+    context: StoreContext<T>,
+    reducer: Reducer<T, A>,
+}
+
+impl<T, A> ReducerStoreContext<T, A> {
+    /// Creates a new `ReducerStoreContext` with the given `initial_state` and `reducer`.
+    pub fn new(initial_state: T, reducer: impl Fn(&T, A) -> T + 'static) -> Self {
+        Self {
+            context: StoreContext::new(initial_state),
+            reducer: Rc::new(reducer),
+        }
+    }
+
+    /// Compute the next state from the current state and `action`, then set it as the store state.
+    /// ```rust
+    /// use yewv::StoreContext;
+    ///
+    /// enum Action {
+    ///     Increment,
+    ///     Decrement,
+    /// }
+    ///
+    /// let store = StoreContext::new_reducer(0, |state, action| match action {
+    ///     Action::Increment => state + 1,
+    ///     Action::Decrement => state - 1,
+    /// });
+    /// store.dispatch(Action::Increment);
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn dispatch(&self, action: A) {
+        let next = (self.reducer)(&self.context.state(), action);
+        self.context.set_state(next);
+    }
+
+    /// Give the underlying store context, to be registered with `ContextProvider<StoreContext<T>>`.
+    pub fn context(&self) -> StoreContext<T> {
+        self.context.clone()
+    }
+}
+
+impl<T> StoreContext<T> {
+    /// Creates a new `ReducerStoreContext` with the given `initial_state` and `reducer`.
+    pub fn new_reducer<A>(
+        initial_state: T,
+        reducer: impl Fn(&T, A) -> T + 'static,
+    ) -> ReducerStoreContext<T, A> {
+        ReducerStoreContext::new(initial_state, reducer)
+    }
+}
+
+impl<T, A> PartialEq for ReducerStoreContext<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+}
+
+impl<T, A> Deref for ReducerStoreContext<T, A> {
+    type Target = StoreContext<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.context
+    }
+}
+
+impl<T, A> Clone for ReducerStoreContext<T, A> {
+    fn clone(&self) -> Self {
+        Self {
+            context: self.context.clone(),
+            reducer: self.reducer.clone(),
+        }
+    }
+}
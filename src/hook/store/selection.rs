@@ -0,0 +1,211 @@
+use super::Store;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+type Listener<M> = (Rc<Cell<bool>>, Box<dyn Fn(&M)>);
+
+struct SelectionState<M> {
+    value: RefCell<Rc<M>>,
+    listeners: RefCell<Vec<Listener<M>>>,
+}
+
+/// Value selected from a store state, kept up to date for as long as the selection is alive.
+/// Once every clone of the selection is dropped, it is detached from the store on the next change.
+pub struct Selection<M> {
+    state: Rc<SelectionState<M>>,
+}
+
+impl<M> Selection<M> {
+    /// Give the current selected value.
+    pub fn get(&self) -> Rc<M> {
+        self.state.value.borrow().clone()
+    }
+
+    /// Invoke the `callback` with the new selected value, every time it changes.
+    /// The `callback` is invoked for as long as the returned handle is kept alive.
+    pub fn on_change(&self, callback: impl Fn(&M) + 'static) -> SubscriptionHandle {
+        let active = Rc::new(Cell::new(true));
+        self.state
+            .listeners
+            .borrow_mut()
+            .push((active.clone(), Box::new(callback)));
+        SubscriptionHandle { active }
+    }
+}
+
+impl<M> Clone for Selection<M> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+/// Handle keeping a callback subscribed.
+/// The callback is unsubscribed when the handle is dropped, unless `forget` was called.
+#[must_use = "the callback is unsubscribed when the handle is dropped"]
+pub struct SubscriptionHandle {
+    pub(crate) active: Rc<Cell<bool>>,
+}
+
+impl SubscriptionHandle {
+    /// Unsubscribe the callback.
+    pub fn unsubscribe(self) {}
+
+    /// Keep the callback subscribed for as long as its source is alive.
+    pub fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for SubscriptionHandle {
+    fn drop(&mut self) {
+        self.active.set(false);
+    }
+}
+
+impl<T: 'static> Store<T> {
+    /// Select a value from the store state, recomputed on every change.
+    /// Listeners of the selection are only invoked when the selected value changed.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// struct AppState {
+    ///     user_id: u32,
+    ///     clicks: u32,
+    /// }
+    ///
+    /// let store = Store::new(AppState { user_id: 1, clicks: 0 });
+    /// let user_id = store.select(|state| state.user_id);
+    /// let _handle = user_id.on_change(|id| println!("User changed to {}", id));
+    /// store.set_state(AppState { user_id: 2, clicks: 0 });
+    /// assert_eq!(*user_id.get(), 2);
+    /// ```
+    pub fn select<M: PartialEq + 'static>(
+        &self,
+        selector: impl Fn(&T) -> M + 'static,
+    ) -> Selection<M> {
+        let state = Rc::new(SelectionState {
+            value: RefCell::new(Rc::new(selector(&self.state_ref()))),
+            listeners: RefCell::new(vec![]),
+        });
+        self.subscribe({
+            let state = Rc::downgrade(&state);
+            move |_, next| {
+                let Some(state) = state.upgrade() else {
+                    return false;
+                };
+                let value = selector(next);
+                if value == **state.value.borrow() {
+                    return true;
+                }
+                let value = Rc::new(value);
+                *state.value.borrow_mut() = value.clone();
+                let mut listeners = std::mem::take(&mut *state.listeners.borrow_mut());
+                listeners.retain(|(active, listener)| {
+                    if active.get() {
+                        listener(&value);
+                    }
+                    active.get()
+                });
+                let mut added = std::mem::replace(&mut *state.listeners.borrow_mut(), listeners);
+                state.listeners.borrow_mut().append(&mut added);
+                true
+            }
+        });
+        Selection { state }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestContext {
+        store: Store<(i32, i32)>,
+        selection: Selection<i32>,
+        notified_values: Rc<RefCell<Vec<i32>>>,
+        handle: SubscriptionHandle,
+    }
+
+    fn setup() -> TestContext {
+        let store = Store::new((0, 0));
+        let selection = store.select(|state| state.0);
+        let notified_values = Rc::new(RefCell::new(vec![]));
+        let handle = selection.on_change({
+            let notified_values = notified_values.clone();
+            move |value| notified_values.borrow_mut().push(*value)
+        });
+        TestContext {
+            store,
+            selection,
+            notified_values,
+            handle,
+        }
+    }
+
+    #[test]
+    fn get_with_selected_value_changed_should_give_new_value() {
+        //Given
+        let ctx = setup();
+        //When
+        ctx.store.set_state((1, 0));
+        //Then
+        assert_eq!(*ctx.selection.get(), 1);
+    }
+
+    #[test]
+    fn on_change_with_selected_value_changed_should_notify_new_value() {
+        //Given
+        let ctx = setup();
+        //When
+        ctx.store.set_state((1, 0));
+        //Then
+        assert_eq!(*ctx.notified_values.borrow(), &[1]);
+    }
+
+    #[test]
+    fn on_change_with_selected_value_unchanged_should_not_notify() {
+        //Given
+        let ctx = setup();
+        //When
+        ctx.store.set_state((0, 1));
+        //Then
+        assert!(ctx.notified_values.borrow().is_empty());
+    }
+
+    #[test]
+    fn on_change_with_handle_dropped_should_no_longer_notify() {
+        //Given
+        let ctx = setup();
+        ctx.handle.unsubscribe();
+        //When
+        ctx.store.set_state((1, 0));
+        //Then
+        assert!(ctx.notified_values.borrow().is_empty());
+    }
+
+    #[test]
+    fn on_change_with_handle_forgotten_should_keep_notifying() {
+        //Given
+        let ctx = setup();
+        ctx.handle.forget();
+        //When
+        ctx.store.set_state((1, 0));
+        //Then
+        assert_eq!(*ctx.notified_values.borrow(), &[1]);
+    }
+
+    #[test]
+    fn select_with_selection_dropped_should_detach_from_store() {
+        //Given
+        let ctx = setup();
+        drop(ctx.selection);
+        //When
+        ctx.store.set_state((1, 0));
+        //Then
+        assert_eq!(ctx.store.subscriber_count(), 0);
+    }
+}
@@ -0,0 +1,187 @@
+use std::{cell::RefCell, rc::Rc};
+
+type Select<T, M> = Rc<dyn Fn(&Rc<T>) -> Rc<M>>;
+
+/// Projection of a store state, computed once per state and shared by every component using it.
+/// A selector is meant to be created once, for instance in a service, then used with `map_selector`.
+/// ```rust
+/// use std::rc::Rc;
+/// use yewv::Selector;
+///
+/// struct AppState {
+///     items: Vec<i32>,
+/// }
+///
+/// let even_items = Selector::new(|state: &AppState| {
+///     state.items.iter().copied().filter(|i| i % 2 == 0).collect::<Vec<_>>()
+/// });
+/// let state = Rc::new(AppState { items: vec![1, 2, 3, 4] });
+/// assert_eq!(*even_items.get(&state), vec![2, 4]);
+/// assert!(Rc::ptr_eq(&even_items.get(&state), &even_items.get(&state)));
+/// ```
+pub struct Selector<T, M> {
+    select: Select<T, M>,
+}
+
+impl<T: 'static, M: 'static> Selector<T, M> {
+    /// Creates a new `Selector` computing its value with `projection`.
+    /// The value is only recomputed when given a different state than the last one.
+    pub fn new(projection: impl Fn(&T) -> M + 'static) -> Self {
+        let cache = RefCell::new(None::<(Rc<T>, Rc<M>)>);
+        Self {
+            select: Rc::new(move |state| {
+                if let Some((input, output)) = &*cache.borrow() {
+                    if Rc::ptr_eq(input, state) {
+                        return output.clone();
+                    }
+                }
+                let output = Rc::new(projection(state));
+                *cache.borrow_mut() = Some((state.clone(), output.clone()));
+                output
+            }),
+        }
+    }
+
+    /// Creates a new `Selector` computing its value with `projection` from the values of selectors `a` and `b`.
+    /// The value is only recomputed when the value of `a` or `b` was recomputed.
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use yewv::Selector;
+    ///
+    /// let sum = Selector::new(|state: &(i32, i32)| state.0 + state.1);
+    /// let product = Selector::new(|state: &(i32, i32)| state.0 * state.1);
+    /// let both = Selector::combine(&sum, &product, |sum, product| format!("{} {}", sum, product));
+    /// assert_eq!(*both.get(&Rc::new((2, 3))), "5 6");
+    /// ```
+    pub fn combine<A: 'static, B: 'static>(
+        a: &Selector<T, A>,
+        b: &Selector<T, B>,
+        projection: impl Fn(&A, &B) -> M + 'static,
+    ) -> Self {
+        let (a, b) = (a.clone(), b.clone());
+        let cache = RefCell::new(None::<(Rc<A>, Rc<B>, Rc<M>)>);
+        Self {
+            select: Rc::new(move |state| {
+                let (a, b) = (a.get(state), b.get(state));
+                if let Some((input_a, input_b, output)) = &*cache.borrow() {
+                    if Rc::ptr_eq(input_a, &a) && Rc::ptr_eq(input_b, &b) {
+                        return output.clone();
+                    }
+                }
+                let output = Rc::new(projection(&a, &b));
+                *cache.borrow_mut() = Some((a, b, output.clone()));
+                output
+            }),
+        }
+    }
+}
+
+impl<T, M> Selector<T, M> {
+    /// Give the value selected from `state`.
+    pub fn get(&self, state: &Rc<T>) -> Rc<M> {
+        (self.select)(state)
+    }
+}
+
+impl<T, M> Clone for Selector<T, M> {
+    fn clone(&self) -> Self {
+        Self {
+            select: self.select.clone(),
+        }
+    }
+}
+
+impl<T, M> PartialEq for Selector<T, M> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.select, &other.select)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Store;
+    use std::cell::Cell;
+
+    struct TestContext {
+        selector: Selector<(i32, i32), i32>,
+        runs: Rc<Cell<usize>>,
+    }
+
+    fn setup() -> TestContext {
+        let runs = Rc::new(Cell::new(0));
+        let selector = Selector::new({
+            let runs = runs.clone();
+            move |state: &(i32, i32)| {
+                runs.set(runs.get() + 1);
+                state.0
+            }
+        });
+        TestContext { selector, runs }
+    }
+
+    #[test]
+    fn get_with_same_state_should_compute_once() {
+        //Given
+        let ctx = setup();
+        let state = Rc::new((1, 0));
+        //When
+        for _ in 0..10 {
+            ctx.selector.get(&state);
+        }
+        //Then
+        assert_eq!(ctx.runs.get(), 1);
+    }
+
+    #[test]
+    fn get_with_new_state_should_compute_again() {
+        //Given
+        let ctx = setup();
+        ctx.selector.get(&Rc::new((1, 0)));
+        //When
+        let value = ctx.selector.get(&Rc::new((2, 0)));
+        //Then
+        assert_eq!(*value, 2);
+        assert_eq!(ctx.runs.get(), 2);
+    }
+
+    #[test]
+    fn get_with_many_subscribers_should_compute_once_per_transition() {
+        //Given
+        let ctx = setup();
+        let store = Store::new((0, 0));
+        for _ in 0..10 {
+            let selector = ctx.selector.clone();
+            store.subscribe_rc(move |_, next| {
+                selector.get(next);
+                true
+            });
+        }
+        //When
+        store.set_state((1, 0));
+        store.set_state((2, 0));
+        //Then
+        assert_eq!(ctx.runs.get(), 2);
+    }
+
+    #[test]
+    fn combine_with_unchanged_selected_values_should_not_compute_again() {
+        //Given
+        let ctx = setup();
+        let runs = Rc::new(Cell::new(0));
+        let combined = Selector::combine(&ctx.selector, &ctx.selector, {
+            let runs = runs.clone();
+            move |a, b| {
+                runs.set(runs.get() + 1);
+                a + b
+            }
+        });
+        let state = Rc::new((1, 0));
+        //When
+        combined.get(&state);
+        let value = combined.get(&state);
+        //Then
+        assert_eq!(*value, 2);
+        assert_eq!(runs.get(), 1);
+    }
+}
@@ -1,13 +1,61 @@
+use super::{
+    history::{History, Transition},
+    metrics::MetricsRecorder,
+    Decision, Differ, Middleware,
+};
 use std::{
-    cell::{Ref, RefCell},
+    cell::{Cell, Ref, RefCell},
+    fmt::{self, Debug},
     rc::Rc,
 };
 
+/// Counters describing the subscription activity of a store over its lifetime.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StoreStats {
+    /// Number of times a subscription was notified of a change.
+    pub notifications: usize,
+    /// Number of subscriptions added to the store.
+    pub subscriptions_added: usize,
+    /// Number of subscriptions dropped by the store.
+    pub subscriptions_dropped: usize,
+}
+
+/// Subscription called with the version of the current state, whether the notification was forced,
+/// and the previous and current states.
+type Subscription<T> = Box<dyn Fn(u64, bool, &Rc<T>, &Rc<T>) -> bool>;
+
+/// Subscriptions taken out of a store while notifying them.
+/// They are given back to the store when dropped, including when a subscription panics.
+struct TakenSubscriptions<'a, T> {
+    subscriptions: &'a RefCell<Vec<Subscription<T>>>,
+    taken: Vec<Subscription<T>>,
+}
+
+impl<T> Drop for TakenSubscriptions<'_, T> {
+    fn drop(&mut self) {
+        // The taken vector is given back to keep its capacity, followed by the subscriptions added while notifying.
+        let mut subscriptions = self.subscriptions.borrow_mut();
+        let added = std::mem::replace(&mut *subscriptions, std::mem::take(&mut self.taken));
+        subscriptions.extend(added);
+    }
+}
+
 /// Simple store with subscription capability.
 pub struct Store<T> {
+    initial_state: Rc<T>,
     previous_state: RefCell<Rc<T>>,
     state: RefCell<Rc<T>>,
-    subscriptions: RefCell<Vec<Box<dyn Fn(&T, &T) -> bool>>>,
+    subscriptions: RefCell<Vec<Subscription<T>>>,
+    middlewares: RefCell<Vec<Box<dyn Middleware<T>>>>,
+    differ: Option<Box<dyn Differ<T>>>,
+    history: RefCell<Option<History<T>>>,
+    notifications: Cell<usize>,
+    subscriptions_added: Cell<usize>,
+    subscriptions_dropped: Cell<usize>,
+    scheduler: RefCell<Option<Box<dyn Fn()>>>,
+    notification_pending: Cell<bool>,
+    version: Cell<u64>,
+    pub(crate) metrics: MetricsRecorder,
 }
 
 impl<T> Store<T> {
@@ -21,9 +69,59 @@ impl<T> Store<T> {
     pub fn new(initial_state: T) -> Self {
         let state = Rc::new(initial_state);
         Self {
+            initial_state: state.clone(),
             previous_state: RefCell::new(state.clone()),
             state: RefCell::new(state),
             subscriptions: RefCell::new(vec![]),
+            middlewares: RefCell::new(vec![]),
+            differ: None,
+            history: RefCell::new(None),
+            notifications: Cell::new(0),
+            subscriptions_added: Cell::new(0),
+            subscriptions_dropped: Cell::new(0),
+            scheduler: RefCell::new(None),
+            notification_pending: Cell::new(false),
+            version: Cell::new(0),
+            metrics: MetricsRecorder::default(),
+        }
+    }
+
+    /// Create a new instance of a store with room for `subs_hint` subscriptions before reallocating.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::with_capacity_hint(0, 64);
+    /// assert_eq!(store.subscriber_count(), 0);
+    /// ```
+    pub fn with_capacity_hint(initial_state: T, subs_hint: usize) -> Self {
+        Self {
+            subscriptions: RefCell::new(Vec::with_capacity(subs_hint)),
+            ..Self::new(initial_state)
+        }
+    }
+
+    /// Create a new instance of a store using `differ` to detect state changes, as with `StoreContext::new_with_differ`.
+    pub(crate) fn with_differ(initial_state: T, differ: impl Differ<T> + 'static) -> Self {
+        Self {
+            differ: Some(Box::new(differ)),
+            ..Self::new(initial_state)
+        }
+    }
+
+    /// Create a new instance of a store recording up to `capacity` previous states,
+    /// which can be restored with `undo` and `redo`.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::with_history(0, 10);
+    /// store.set_state(1);
+    /// store.undo();
+    /// assert_eq!(*store.state(), 0);
+    /// ```
+    pub fn with_history(initial_state: T, capacity: usize) -> Self {
+        Self {
+            history: RefCell::new(Some(History::new(capacity))),
+            ..Self::new(initial_state)
         }
     }
 
@@ -40,7 +138,21 @@ impl<T> Store<T> {
         self.state.borrow().clone()
     }
 
+    /// Give a reference to the store state prior to the last change.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// store.set_state(1);
+    /// assert_eq!(*store.previous_state(), 0);
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn previous_state(&self) -> Rc<T> {
+        self.previous_state.borrow().clone()
+    }
+
     /// Set store next state.
+    /// When the next state is already shared in an `Rc`, use `set_state_rc` to set it without allocating again.
     /// ```rust
     /// use yewv::Store;
     ///
@@ -50,12 +162,187 @@ impl<T> Store<T> {
     /// assert_eq!(*store.state(), 1);
     /// ```
     pub fn set_state(&self, new_state: T) {
-        {
-            let mut state = self.state.borrow_mut();
-            *self.previous_state.borrow_mut() = state.clone();
-            *state = Rc::new(new_state);
+        self.commit(Rc::new(new_state));
+    }
+
+    /// Set store next state and return the replaced state, which becomes the previous state.
+    /// When a middleware cancels the change, the returned state is still the current state.
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(vec![1, 2]);
+    /// let pending = store.replace_state(vec![]);
+    /// assert_eq!(*pending, vec![1, 2]);
+    /// assert!(Rc::ptr_eq(&pending, &store.previous_state()));
+    /// ```
+    pub fn replace_state(&self, new_state: T) -> Rc<T> {
+        let replaced = self.state();
+        self.commit(Rc::new(new_state));
+        replaced
+    }
+
+    /// Set store next state from an already shared state.
+    /// Nothing happens when `new_state` is already the current state.
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// let state = Rc::new(1);
+    /// store.set_state_rc(state.clone());
+    /// assert!(Rc::ptr_eq(&store.state(), &state));
+    /// ```
+    pub fn set_state_rc(&self, new_state: Rc<T>) {
+        if Rc::ptr_eq(&self.state_ref(), &new_state) {
+            return;
+        }
+        self.commit(new_state);
+    }
+
+    /// Give a snapshot of the current store state, to be restored later with `restore`.
+    /// States are never mutated by the store, taking a snapshot only clones the `Rc`.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// let snapshot = store.snapshot();
+    /// store.set_state(1);
+    /// assert_eq!(*snapshot, 0);
+    /// ```
+    pub fn snapshot(&self) -> Rc<T> {
+        self.state()
+    }
+
+    /// Transform the current state into another representation, such as a subset of the state to send elsewhere.
+    /// As opposed to the `map` hook, `map_into` doesn't subscribe to the store and doesn't observe future changes.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(vec![1, 2, 3]);
+    /// let total: i32 = store.map_into(|state| state.iter().sum());
+    /// store.set_state(vec![]);
+    /// assert_eq!(total, 6);
+    /// ```
+    pub fn map_into<U>(&self, f: impl FnOnce(&T) -> U) -> U {
+        // The state is cloned out of the store so that `f` may change it.
+        let state = self.state();
+        f(&state)
+    }
+
+    /// Set a snapshot taken with `snapshot` as store next state.
+    /// Subscribers are notified like with any other change, and the replaced state becomes the previous state.
+    /// Nothing happens when `snapshot` is already the current state.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// let snapshot = store.snapshot();
+    /// store.set_state(1);
+    /// store.restore(snapshot);
+    /// assert_eq!(*store.state(), 0);
+    /// assert_eq!(*store.previous_state(), 1);
+    /// ```
+    pub fn restore(&self, snapshot: Rc<T>) {
+        self.set_state_rc(snapshot);
+    }
+
+    /// Set store next state only when `pred` accepts the current state.
+    /// Return whether the state changed. Subscribers are not notified when `pred` rejects the change.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// assert!(!store.set_state_if(|state| *state > 0, 1));
+    /// assert!(store.set_state_if(|state| *state == 0, 1));
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn set_state_if(&self, pred: impl FnOnce(&T) -> bool, new_state: T) -> bool {
+        if !pred(&self.state()) {
+            return false;
+        }
+        self.commit(Rc::new(new_state))
+    }
+
+    /// Compute store next state from the current state only when `pred` accepts it.
+    /// Return whether the state changed. Neither `f` is called nor subscribers are notified when `pred` rejects the change.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(vec![1, 2]);
+    /// assert!(store.update_if(|items| items.contains(&2), |items| items[1..].to_vec()));
+    /// assert!(!store.update_if(|items| items.contains(&1), |items| items[1..].to_vec()));
+    /// assert_eq!(*store.state(), vec![2]);
+    /// ```
+    pub fn update_if(&self, pred: impl FnOnce(&T) -> bool, f: impl FnOnce(&T) -> T) -> bool {
+        let next = {
+            let state = self.state();
+            if !pred(&state) {
+                return false;
+            }
+            f(&state)
+        };
+        self.commit(Rc::new(next))
+    }
+
+    /// Compute store next state from the current state, unless `f` fails.
+    /// On error, the store is left untouched, subscribers are not notified and the error is returned.
+    /// Otherwise, return whether the state changed, which it does not when a middleware cancels the change.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let decrement = |state: &u32| state.checked_sub(1).ok_or("Count is already zero");
+    /// let store = Store::new(1);
+    /// assert_eq!(store.try_update(decrement), Ok(true));
+    /// assert_eq!(store.try_update(decrement), Err("Count is already zero"));
+    /// assert_eq!(*store.state(), 0);
+    /// ```
+    pub fn try_update<E>(&self, f: impl FnOnce(&T) -> Result<T, E>) -> Result<bool, E> {
+        let next = f(&self.state())?;
+        Ok(self.commit(Rc::new(next)))
+    }
+
+    /// Restore the store to the state it was created with.
+    /// Subscribers are notified like with any other change.
+    /// Nothing happens when the store already holds its initial state.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// store.set_state(1);
+    /// store.reset();
+    /// assert_eq!(*store.state(), 0);
+    /// assert_eq!(*store.previous_state(), 1);
+    /// ```
+    pub fn reset(&self) {
+        if Rc::ptr_eq(&self.state_ref(), &self.initial_state) {
+            return;
         }
-        self.notify();
+        self.commit(self.initial_state.clone());
+    }
+
+    /// Notify subscribers without changing the store state.
+    /// This is the escape hatch for states mutated in place through interior mutability (e.g. `RefCell`).
+    ///
+    /// Subscribers are called with the same state as previous and next state.
+    /// Since comparing a state with itself can't detect a change, every component subscribed
+    /// with `map`, `map_ref`, `watch` or `watch_ref` will re-render, regardless of the observed value.
+    /// Components only reading the state with `peek` are not re-rendered.
+    ///
+    /// This defeats the comparisons which keep renders to a minimum and should be used sparingly.
+    /// Prefer setting a new state whenever possible.
+    /// ```rust
+    /// use std::cell::RefCell;
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(RefCell::new(vec![0]));
+    /// store.state().borrow_mut().push(1);
+    /// store.force_notify();
+    /// ```
+    pub fn force_notify(&self) {
+        self.notification_pending.set(false);
+        *self.previous_state.borrow_mut() = self.state.borrow().clone();
+        self.notify(true);
     }
 
     /// Subscibe to changes made to the store state.
@@ -71,118 +358,1627 @@ impl<T> Store<T> {
     /// } );
     /// ```
     pub fn subscribe(&self, callback: impl Fn(&T, &T) -> bool + 'static) {
-        self.subscriptions.borrow_mut().push(Box::from(callback));
+        self.subscribe_rc(move |prev, next| callback(prev, next));
     }
 
-    pub(crate) fn notify(&self) {
-        let mut subs = std::mem::take(&mut *self.subscriptions.borrow_mut());
-        let previous = &self.previous_state.borrow();
-        let next = &self.state_ref();
-        subs.retain(|s| s(previous, next));
-        self.subscriptions.borrow_mut().append(&mut subs);
+    /// Subscribe to changes made to the store state, with the shared previous and current states.
+    pub(crate) fn subscribe_rc(&self, callback: impl Fn(&Rc<T>, &Rc<T>) -> bool + 'static) {
+        self.add_subscription(Box::new(move |_, _, prev, next| callback(prev, next)));
     }
 
-    pub(crate) fn state_ref(&self) -> Ref<Rc<T>> {
-        self.state.borrow()
+    /// Subscribe to changes made to the store state, knowing whether the notification was forced with `force_notify`.
+    pub(crate) fn subscribe_forced(
+        &self,
+        callback: impl Fn(bool, &Rc<T>, &Rc<T>) -> bool + 'static,
+    ) {
+        self.add_subscription(Box::new(move |_, forced, prev, next| {
+            callback(forced, prev, next)
+        }));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Subscribe to changes made to the store state, with the version of the current state.
+    /// As with `subscribe`, the subscription is dropped when the `callback` returns `false`.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// store.subscribe_versioned(|version, prev_state, current_state| {
+    ///     /* Put your own subscription logic, `version` being the one of `current_state`. */
+    ///     true
+    /// });
+    /// ```
+    pub fn subscribe_versioned(&self, callback: impl Fn(u64, &T, &T) -> bool + 'static) {
+        self.add_subscription(Box::new(move |version, _, prev, next| {
+            callback(version, prev, next)
+        }));
+    }
 
-    struct TestContext<T> {
-        notified_values: Rc<RefCell<Vec<(T, T)>>>,
-        is_sub_active: Rc<RefCell<bool>>,
-        store: Store<T>,
+    fn add_subscription(&self, subscription: Subscription<T>) {
+        self.subscriptions.borrow_mut().push(subscription);
+        self.subscriptions_added
+            .set(self.subscriptions_added.get() + 1);
     }
 
-    fn setup<T: Clone + 'static>(initial_state: T) -> TestContext<T> {
-        let store = Store::new(initial_state);
-        let notified_values = Rc::new(RefCell::new(vec![]));
-        let is_sub_active = Rc::new(RefCell::new(true));
-        store.subscribe({
-            let notified_values = notified_values.clone();
-            let is_sub_active = is_sub_active.clone();
-            move |prev, next| {
-                notified_values
-                    .borrow_mut()
-                    .push((prev.clone(), next.clone()));
-                *is_sub_active.borrow()
-            }
-        });
-        TestContext {
-            notified_values,
-            is_sub_active,
-            store,
+    /// Invoke the `callback` immediately with the current state as both previous and current state,
+    /// then subscribe it to changes made to the store state.
+    /// When the immediate invocation returns `false`, the `callback` is not subscribed.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// store.subscribe_with_current(|_, current_state| {
+    ///     /* Put your own logic, run now and on every change. */
+    ///     true
+    /// });
+    /// ```
+    pub fn subscribe_with_current(&self, callback: impl Fn(&T, &T) -> bool + 'static) {
+        let current = self.state();
+        if callback(&current, &current) {
+            self.subscribe(callback);
         }
     }
 
-    #[test]
-    fn set_state_with_new_state_should_update_current_state() {
-        //Given
-        let ctx = setup(0);
-        //When
-        ctx.store.set_state(1);
-        //Then
-        assert_eq!(*ctx.store.state(), 1);
+    /// Restore the state preceding the current one in the history.
+    /// Subscribers are notified like with any other change.
+    /// Return `false` when there is nothing to undo or when the store has no history.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::with_history(0, 10);
+    /// store.set_state(1);
+    /// assert!(store.undo());
+    /// assert_eq!(*store.state(), 0);
+    /// assert!(!store.undo());
+    /// ```
+    pub fn undo(&self) -> bool {
+        let previous = self.history.borrow().as_ref().and_then(History::previous);
+        match previous {
+            Some(previous) => self.transition(previous, Transition::Undo),
+            None => false,
+        }
     }
 
-    #[test]
-    fn set_state_with_new_state_should_update_previous_state() {
-        //Given
-        let ctx = setup(0);
-        ctx.store.set_state(1);
-        //When
-        ctx.store.set_state(2);
-        //Then
-        assert_eq!(**ctx.store.previous_state.borrow(), 1);
+    /// Restore the state that was last undone.
+    /// Setting a new state after an undo discards the states that could have been redone.
+    /// Return `false` when there is nothing to redo or when the store has no history.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::with_history(0, 10);
+    /// store.set_state(1);
+    /// store.undo();
+    /// assert!(store.redo());
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn redo(&self) -> bool {
+        let next = self.history.borrow().as_ref().and_then(History::next);
+        match next {
+            Some(next) => self.transition(next, Transition::Redo),
+            None => false,
+        }
     }
 
-    #[test]
-    fn set_state_with_new_state_should_notify() {
-        //Given
-        let ctx = setup(0);
-        //When
-        ctx.store.set_state(1);
-        //Then
-        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1)]);
+    /// Tell whether `undo` would restore a state.
+    pub fn can_undo(&self) -> bool {
+        self.history
+            .borrow()
+            .as_ref()
+            .is_some_and(|h| h.previous().is_some())
     }
 
-    #[test]
-    fn set_state_with_subscription_no_longer_active_should_no_longer_notify() {
-        //Given
-        let ctx = setup(0);
-        *ctx.is_sub_active.borrow_mut() = false;
-        ctx.store.set_state(1);
-        let notify_count = ctx.notified_values.borrow().len();
-        //When
-        ctx.store.set_state(2);
-        //Then
-        assert_eq!(ctx.notified_values.borrow().len(), notify_count);
+    /// Tell whether `redo` would restore a state.
+    pub fn can_redo(&self) -> bool {
+        self.history
+            .borrow()
+            .as_ref()
+            .is_some_and(|h| h.next().is_some())
     }
 
-    #[test]
-    fn set_state_with_subscription_no_longer_active_should_drop_subscription() {
-        //Given
-        let ctx = setup(0);
-        let sub_count = ctx.store.subscriptions.borrow().len();
-        *ctx.is_sub_active.borrow_mut() = false;
-        ctx.store.set_state(1);
-        //When
-        ctx.store.set_state(2);
-        //Then
-        assert_eq!(ctx.store.subscriptions.borrow().len(), sub_count - 1);
+    /// Give the recorded previous states, from the oldest to the most recent.
+    /// The current state is not part of the history. A store created without history has none.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::with_history(0, 2);
+    /// store.set_state(1);
+    /// store.set_state(2);
+    /// store.set_state(3);
+    /// let history: Vec<i32> = store.history().iter().map(|s| **s).collect();
+    /// assert_eq!(history, [1, 2]);
+    /// ```
+    pub fn history(&self) -> Vec<Rc<T>> {
+        self.history
+            .borrow()
+            .as_ref()
+            .map(History::past)
+            .unwrap_or_default()
     }
 
-    #[test]
-    fn subscribe_with_callback_should_add_callback_to_subscriptions() {
-        //Given
-        let ctx = setup(0);
-        let sub_count = ctx.store.subscriptions.borrow().len();
-        //When
-        ctx.store.subscribe(|_, _| false);
-        //Then
-        assert_eq!(ctx.store.subscriptions.borrow().len(), sub_count + 1);
+    /// Register a middleware running before and after every state transition.
+    /// Middlewares run in registration order and, unlike subscriptions, are never dropped.
+    /// A closure taking the previous and next states can be registered directly,
+    /// it is then invoked on every notification before subscribers.
+    /// ```rust
+    /// use yewv::{Middleware, Store};
+    ///
+    /// struct Printer;
+    ///
+    /// impl Middleware<i32> for Printer {
+    ///     fn after(&self, prev: &i32, next: &i32) {
+    ///         println!("{} -> {}", prev, next);
+    ///     }
+    /// }
+    ///
+    /// let store = Store::new(0);
+    /// store.add_middleware(Printer);
+    /// ```
+    pub fn add_middleware(&self, middleware: impl Middleware<T> + 'static) {
+        self.middlewares.borrow_mut().push(Box::new(middleware));
+    }
+
+    /// Give the number of active subscriptions.
+    /// Inactive subscriptions are only dropped on the next change, they are counted until then.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// store.subscribe(|_, _| true);
+    /// assert_eq!(store.subscriber_count(), 1);
+    /// ```
+    pub fn subscriber_count(&self) -> usize {
+        self.subscriptions.borrow().len()
+    }
+
+    /// Give the number of subscriptions currently registered, as `subscriber_count` does.
+    /// Useful to check in tests that unmounted components dropped their subscriptions.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// store.subscribe(|_, _| false);
+    /// store.set_state(1);
+    /// assert_eq!(store.subscription_count(), 0);
+    /// ```
+    pub fn subscription_count(&self) -> usize {
+        self.subscriber_count()
+    }
+
+    /// Drop every subscription of the store, to reuse it from a clean slate between tests.
+    /// Subscribed components are no longer notified of changes.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// store.subscribe(|_, _| true);
+    /// store.clear_subscriptions();
+    /// assert_eq!(store.subscriber_count(), 0);
+    /// ```
+    #[cfg(feature = "testing")]
+    pub fn clear_subscriptions(&self) {
+        let count = std::mem::take(&mut *self.subscriptions.borrow_mut()).len();
+        self.subscriptions_dropped
+            .set(self.subscriptions_dropped.get() + count);
+    }
+
+    /// Give the subscription counters accumulated since the store creation.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// store.subscribe(|_, _| false);
+    /// store.set_state(1);
+    /// let stats = store.stats();
+    /// assert_eq!(stats.notifications, 1);
+    /// assert_eq!(stats.subscriptions_added, 1);
+    /// assert_eq!(stats.subscriptions_dropped, 1);
+    /// ```
+    pub fn stats(&self) -> StoreStats {
+        StoreStats {
+            notifications: self.notifications.get(),
+            subscriptions_added: self.subscriptions_added.get(),
+            subscriptions_dropped: self.subscriptions_dropped.get(),
+        }
+    }
+
+    /// Give the version of the current state.
+    /// The version starts at 0 and is incremented on every state change, including `reset`, `undo` and `redo`,
+    /// so two different versions always denote two different states.
+    ///
+    /// In components, the version can be used as a dependency to run effects on any change.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// store.set_state(1);
+    /// store.reset();
+    /// assert_eq!(store.version(), 2);
+    /// ```
+    pub fn version(&self) -> u64 {
+        self.version.get()
+    }
+
+    pub(crate) fn commit(&self, next: Rc<T>) -> bool {
+        self.transition(next, Transition::Commit)
+    }
+
+    fn transition(&self, next: Rc<T>, transition: Transition) -> bool {
+        let current = self.state();
+        if !self.changed(&current, &next) {
+            return false;
+        }
+        let cancelled = self
+            .middlewares
+            .borrow()
+            .iter()
+            .any(|m| m.before(&current, &next) == Decision::Cancel);
+        if cancelled {
+            return false;
+        }
+        if let Some(history) = self.history.borrow_mut().as_mut() {
+            history.record(current.clone(), transition);
+        }
+        let pending = self.notification_pending.get();
+        {
+            let mut state = self.state.borrow_mut();
+            if !pending {
+                *self.previous_state.borrow_mut() = state.clone();
+            }
+            *state = next.clone();
+        }
+        self.version.set(self.version.get() + 1);
+        if let Some(schedule) = self.scheduler.borrow().as_ref() {
+            if !pending {
+                self.notification_pending.set(true);
+                schedule();
+            }
+            return true;
+        }
+        self.notify(false);
+        for middleware in self.middlewares.borrow().iter() {
+            middleware.after(&current, &next);
+        }
+        true
+    }
+
+    /// Defer notifications, calling `schedule` on the first change of each batch.
+    /// The scheduled task is expected to call `flush`.
+    #[cfg_attr(not(any(feature = "async", feature = "timers")), allow(dead_code))]
+    pub(crate) fn defer_notifications(&self, schedule: impl Fn() + 'static) {
+        *self.scheduler.borrow_mut() = Some(Box::new(schedule));
+    }
+
+    /// Notify subscribers of the changes made since the batch started, if any, without waiting for the batch to end.
+    /// Middlewares `after` hooks are called once, with the states before and after the batch.
+    /// Stores notifying subscribers on every change have nothing to flush.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// store.set_state(1);
+    /// store.flush();
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn flush(&self) {
+        if !self.notification_pending.replace(false) {
+            return;
+        }
+        // A batch can end on a state the differ considers unchanged, such as a value set back.
+        if !self.changed(&self.previous_state(), &self.state_ref()) {
+            return;
+        }
+        self.notify(false);
+        let (previous, current) = (self.previous_state(), self.state());
+        for middleware in self.middlewares.borrow().iter() {
+            middleware.after(&previous, &current);
+        }
+    }
+
+    /// Subscribe to the next change made to the store state only.
+    /// The `callback` is invoked once and the subscription is then dropped.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// store.subscribe_once(|prev_state, current_state| {
+    ///     /* Put your own one-shot logic. */
+    /// });
+    /// ```
+    pub fn subscribe_once(&self, callback: impl FnOnce(&T, &T) + 'static) {
+        let callback = RefCell::new(Some(callback));
+        self.subscribe(move |prev, next| {
+            if let Some(callback) = callback.borrow_mut().take() {
+                callback(prev, next);
+            }
+            false
+        });
+    }
+
+    /// Subscribe to changes made to the store state for as long as `owner` is alive.
+    /// Only a weak reference to `owner` is held, the subscription is dropped on the first change following its release.
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use yewv::Store;
+    ///
+    /// struct Service;
+    ///
+    /// let store = Store::new(0);
+    /// let service = Rc::new(Service);
+    /// store.subscribe_weak(&service, |service, prev_state, current_state| {
+    ///     /* Put your own logic. */
+    /// });
+    /// drop(service);
+    /// store.set_state(1);
+    /// assert_eq!(store.subscriber_count(), 0);
+    /// ```
+    pub fn subscribe_weak<O: 'static>(
+        &self,
+        owner: &Rc<O>,
+        callback: impl Fn(&O, &T, &T) + 'static,
+    ) {
+        let owner = Rc::downgrade(owner);
+        self.subscribe(move |prev, next| match owner.upgrade() {
+            Some(owner) => {
+                callback(&owner, prev, next);
+                true
+            }
+            None => false,
+        });
+    }
+
+    /// Create a new store whose state is the value computed from this store state by `f`.
+    /// The derived store is recomputed on every change of this store, but only updated, and its subscribers notified,
+    /// when the derived value changed.
+    /// The derived store is meant to be read only, as any state set on it is replaced on the next change of this store.
+    /// Once the derived store is dropped, it is detached from this store on the next change.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(vec![1, 2, 3]);
+    /// let sum = store.derived(|items| items.iter().sum::<i32>());
+    /// store.set_state(vec![1, 2, 3, 4]);
+    /// assert_eq!(*sum.state(), 10);
+    /// ```
+    pub fn derived<U: PartialEq + 'static>(&self, f: impl Fn(&T) -> U + 'static) -> Rc<Store<U>> {
+        let derived = Rc::new(Store::new(f(&self.state_ref())));
+        self.subscribe({
+            let derived = Rc::downgrade(&derived);
+            move |_, next| match derived.upgrade() {
+                Some(derived) => {
+                    derived.set_state_if_changed(f(next));
+                    true
+                }
+                None => false,
+            }
+        });
+        derived
+    }
+
+    pub(crate) fn notify(&self, forced: bool) {
+        let mut subs = TakenSubscriptions {
+            subscriptions: &self.subscriptions,
+            taken: std::mem::take(&mut *self.subscriptions.borrow_mut()),
+        };
+        let previous = &self.previous_state.borrow();
+        let next = &self.state_ref();
+        for middleware in self.middlewares.borrow().iter() {
+            middleware.notify(previous, next);
+        }
+        let count = subs.taken.len();
+        let version = self.version.get();
+        subs.taken.retain(|s| s(version, forced, previous, next));
+        self.notifications.set(self.notifications.get() + count);
+        self.subscriptions_dropped
+            .set(self.subscriptions_dropped.get() + count - subs.taken.len());
+    }
+
+    /// Tell whether `next` differs from `prev` according to the differ of the store.
+    /// Without a differ, every new state is a change.
+    fn changed(&self, prev: &T, next: &T) -> bool {
+        match &self.differ {
+            Some(differ) => differ.changed(prev, next),
+            None => true,
+        }
+    }
+
+    pub(crate) fn state_ref(&self) -> Ref<Rc<T>> {
+        self.state.borrow()
+    }
+}
+
+impl<T: PartialEq> Store<T> {
+    /// Set store next state only when it differs from the current state.
+    /// Return whether the state changed, in which case subscribers were notified.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// assert!(!store.set_state_if_changed(0));
+    /// assert!(store.set_state_if_changed(1));
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn set_state_if_changed(&self, new_state: T) -> bool {
+        if **self.state_ref() == new_state {
+            return false;
+        }
+        self.commit(Rc::new(new_state))
+    }
+}
+
+impl<T: Default> Store<T> {
+    /// Set the default state as store next state and return the replaced state, which becomes the previous state.
+    /// ```rust
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(vec![1, 2]);
+    /// let buffer = store.take_state();
+    /// assert_eq!(*buffer, vec![1, 2]);
+    /// assert!(store.state().is_empty());
+    /// ```
+    pub fn take_state(&self) -> Rc<T> {
+        self.replace_state(T::default())
+    }
+}
+
+/// Print the current state and the number of subscriptions.
+/// Values being borrowed mutably, such as during a state transition, are printed as `<borrowed>`.
+impl<T: Debug> Debug for Store<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Store");
+        match self.state.try_borrow() {
+            Ok(state) => debug.field("state", &state),
+            Err(_) => debug.field("state", &format_args!("<borrowed>")),
+        };
+        match self.subscriptions.try_borrow() {
+            Ok(subscriptions) => debug.field("subscriptions", &subscriptions.len()),
+            Err(_) => debug.field("subscriptions", &format_args!("<borrowed>")),
+        };
+        debug.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestContext<T> {
+        notified_values: Rc<RefCell<Vec<(T, T)>>>,
+        is_sub_active: Rc<RefCell<bool>>,
+        store: Store<T>,
+    }
+
+    fn setup<T: Clone + 'static>(initial_state: T) -> TestContext<T> {
+        setup_store(Store::new(initial_state))
+    }
+
+    fn setup_store<T: Clone + 'static>(store: Store<T>) -> TestContext<T> {
+        let notified_values = Rc::new(RefCell::new(vec![]));
+        let is_sub_active = Rc::new(RefCell::new(true));
+        store.subscribe({
+            let notified_values = notified_values.clone();
+            let is_sub_active = is_sub_active.clone();
+            move |prev, next| {
+                notified_values
+                    .borrow_mut()
+                    .push((prev.clone(), next.clone()));
+                *is_sub_active.borrow()
+            }
+        });
+        TestContext {
+            notified_values,
+            is_sub_active,
+            store,
+        }
+    }
+
+    #[test]
+    fn set_state_with_new_state_should_update_current_state() {
+        //Given
+        let ctx = setup(0);
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(*ctx.store.state(), 1);
+    }
+
+    #[test]
+    fn set_state_with_new_state_should_update_previous_state() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.set_state(1);
+        //When
+        ctx.store.set_state(2);
+        //Then
+        assert_eq!(**ctx.store.previous_state.borrow(), 1);
+    }
+
+    #[test]
+    fn previous_state_with_new_state_should_return_state_before_change() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.set_state(1);
+        //When
+        let previous = ctx.store.previous_state();
+        //Then
+        assert_eq!(*previous, 0);
+    }
+
+    #[test]
+    fn set_state_with_new_state_should_notify() {
+        //Given
+        let ctx = setup(0);
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1)]);
+    }
+
+    #[test]
+    fn set_state_with_subscription_no_longer_active_should_no_longer_notify() {
+        //Given
+        let ctx = setup(0);
+        *ctx.is_sub_active.borrow_mut() = false;
+        ctx.store.set_state(1);
+        let notify_count = ctx.notified_values.borrow().len();
+        //When
+        ctx.store.set_state(2);
+        //Then
+        assert_eq!(ctx.notified_values.borrow().len(), notify_count);
+    }
+
+    #[test]
+    fn set_state_with_subscription_no_longer_active_should_drop_subscription() {
+        //Given
+        let ctx = setup(0);
+        let sub_count = ctx.store.subscriptions.borrow().len();
+        *ctx.is_sub_active.borrow_mut() = false;
+        ctx.store.set_state(1);
+        //When
+        ctx.store.set_state(2);
+        //Then
+        assert_eq!(ctx.store.subscriptions.borrow().len(), sub_count - 1);
+    }
+
+    #[test]
+    fn set_state_if_changed_with_same_state_should_not_notify() {
+        //Given
+        let ctx = setup(1);
+        //When
+        let changed = ctx.store.set_state_if_changed(1);
+        //Then
+        assert!(!changed);
+        assert!(ctx.notified_values.borrow().is_empty());
+    }
+
+    #[test]
+    fn set_state_if_changed_with_same_state_should_keep_previous_state() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.set_state(1);
+        //When
+        ctx.store.set_state_if_changed(1);
+        //Then
+        assert_eq!(*ctx.store.previous_state(), 0);
+    }
+
+    #[test]
+    fn set_state_if_changed_with_new_state_should_notify() {
+        //Given
+        let ctx = setup(0);
+        //When
+        let changed = ctx.store.set_state_if_changed(1);
+        //Then
+        assert!(changed);
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1)]);
+    }
+
+    #[test]
+    fn restore_with_snapshot_should_notify_restored_state() {
+        //Given
+        let ctx = setup(0);
+        let snapshot = ctx.store.snapshot();
+        ctx.store.set_state(1);
+        //When
+        ctx.store.restore(snapshot.clone());
+        //Then
+        assert!(Rc::ptr_eq(&ctx.store.state(), &snapshot));
+        assert_eq!(*ctx.store.previous_state(), 1);
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn restore_with_current_state_should_keep_previous_state() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.set_state(1);
+        let snapshot = ctx.store.snapshot();
+        //When
+        ctx.store.restore(snapshot);
+        //Then
+        assert_eq!(*ctx.store.previous_state(), 0);
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1)]);
+    }
+
+    #[test]
+    fn restore_with_history_should_undo_to_replaced_state() {
+        //Given
+        let ctx = setup_store(Store::with_history(0, 10));
+        let snapshot = ctx.store.snapshot();
+        ctx.store.set_state(1);
+        ctx.store.restore(snapshot);
+        //When
+        ctx.store.undo();
+        //Then
+        assert_eq!(*ctx.store.state(), 1);
+        assert_eq!(*ctx.store.previous_state(), 0);
+    }
+
+    #[test]
+    fn try_update_with_error_should_leave_store_untouched() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.set_state(1);
+        //When
+        let result = ctx.store.try_update(|_| Err("invalid"));
+        //Then
+        assert_eq!(result, Err("invalid"));
+        assert_eq!(*ctx.store.state(), 1);
+        assert_eq!(*ctx.store.previous_state(), 0);
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1)]);
+    }
+
+    #[test]
+    fn try_update_with_next_state_should_notify() {
+        //Given
+        let ctx = setup(1);
+        //When
+        let result = ctx.store.try_update(|state| Ok::<_, ()>(state + 1));
+        //Then
+        assert_eq!(result, Ok(true));
+        assert_eq!(*ctx.notified_values.borrow(), &[(1, 2)]);
+    }
+
+    #[test]
+    fn try_update_with_cancelling_middleware_should_not_be_changed() {
+        //Given
+        let ctx = setup(1);
+        add_test_middleware(&ctx.store, Decision::Cancel);
+        //When
+        let result = ctx.store.try_update(|state| Ok::<_, ()>(state + 1));
+        //Then
+        assert_eq!(result, Ok(false));
+        assert_eq!(*ctx.store.state(), 1);
+    }
+
+    #[test]
+    fn replace_state_with_new_state_should_return_previous_state() {
+        //Given
+        let ctx = setup(0);
+        let state = ctx.store.state();
+        //When
+        let replaced = ctx.store.replace_state(1);
+        //Then
+        assert!(Rc::ptr_eq(&replaced, &state));
+        assert!(Rc::ptr_eq(&replaced, &ctx.store.previous_state()));
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1)]);
+    }
+
+    #[test]
+    fn replace_state_with_successive_states_should_notify_in_order() {
+        //Given
+        let ctx = setup(0);
+        //When
+        let first = ctx.store.replace_state(1);
+        let second = ctx.store.replace_state(2);
+        //Then
+        assert_eq!((*first, *second), (0, 1));
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn take_state_with_state_should_return_state_and_set_default() {
+        //Given
+        let ctx = setup(1);
+        let state = ctx.store.state();
+        //When
+        let taken = ctx.store.take_state();
+        //Then
+        assert!(Rc::ptr_eq(&taken, &state));
+        assert_eq!(*ctx.store.state(), 0);
+        assert!(Rc::ptr_eq(&ctx.store.previous_state(), &taken));
+    }
+
+    #[test]
+    fn take_state_with_state_should_notify_default_state() {
+        //Given
+        let ctx = setup(1);
+        //When
+        ctx.store.take_state();
+        //Then
+        assert_eq!(*ctx.notified_values.borrow(), &[(1, 0)]);
+    }
+
+    #[test]
+    fn set_state_if_with_rejected_state_should_not_notify() {
+        //Given
+        let ctx = setup(0);
+        //When
+        let changed = ctx.store.set_state_if(|state| *state > 0, 1);
+        //Then
+        assert!(!changed);
+        assert_eq!(*ctx.store.state(), 0);
+        assert!(ctx.notified_values.borrow().is_empty());
+    }
+
+    #[test]
+    fn set_state_if_with_accepted_state_should_notify() {
+        //Given
+        let ctx = setup(0);
+        //When
+        let changed = ctx.store.set_state_if(|state| *state == 0, 1);
+        //Then
+        assert!(changed);
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1)]);
+    }
+
+    #[test]
+    fn update_if_with_rejected_state_should_not_compute_next_state() {
+        //Given
+        let ctx = setup(0);
+        let computed = Rc::new(RefCell::new(false));
+        //When
+        let changed = ctx.store.update_if(|state| *state > 0, {
+            let computed = computed.clone();
+            move |state| {
+                *computed.borrow_mut() = true;
+                state + 1
+            }
+        });
+        //Then
+        assert!(!changed);
+        assert!(!*computed.borrow());
+        assert!(ctx.notified_values.borrow().is_empty());
+    }
+
+    #[test]
+    fn update_if_with_accepted_state_should_set_computed_state() {
+        //Given
+        let ctx = setup(1);
+        //When
+        let changed = ctx.store.update_if(|state| *state > 0, |state| state + 1);
+        //Then
+        assert!(changed);
+        assert_eq!(*ctx.notified_values.borrow(), &[(1, 2)]);
+    }
+
+    #[test]
+    fn reset_with_changed_state_should_restore_initial_state() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.set_state(1);
+        //When
+        ctx.store.reset();
+        //Then
+        assert_eq!(*ctx.store.state(), 0);
+    }
+
+    #[test]
+    fn reset_with_changed_state_should_update_previous_state() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.set_state(1);
+        //When
+        ctx.store.reset();
+        //Then
+        assert_eq!(*ctx.store.previous_state(), 1);
+    }
+
+    #[test]
+    fn reset_with_changed_state_should_notify() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.set_state(1);
+        //When
+        ctx.store.reset();
+        //Then
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn reset_with_initial_state_should_not_notify() {
+        //Given
+        let ctx = setup(0);
+        //When
+        ctx.store.reset();
+        //Then
+        assert!(ctx.notified_values.borrow().is_empty());
+        assert_eq!(ctx.store.version(), 0);
+    }
+
+    #[test]
+    fn set_state_with_panicking_subscription_should_keep_notifying_other_subscriptions() {
+        //Given
+        let store = Store::new(0);
+        let notified_values = Rc::new(RefCell::new(vec![]));
+        for i in 0..3 {
+            let notified_values = notified_values.clone();
+            store.subscribe(move |_, next: &i32| {
+                if i == 1 && *next == 1 {
+                    panic!("Subscription panicked");
+                }
+                notified_values.borrow_mut().push((i, *next));
+                true
+            });
+        }
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| store.set_state(1)));
+        //When
+        store.set_state(2);
+        //Then
+        assert!(result.is_err());
+        assert_eq!(*notified_values.borrow(), &[(0, 1), (0, 2), (1, 2), (2, 2)]);
+    }
+
+    fn setup_deferred() -> (TestContext<i32>, Rc<Cell<usize>>) {
+        let ctx = setup(0);
+        let scheduled = Rc::new(Cell::new(0));
+        ctx.store.defer_notifications({
+            let scheduled = scheduled.clone();
+            move || scheduled.set(scheduled.get() + 1)
+        });
+        (ctx, scheduled)
+    }
+
+    #[test]
+    fn set_state_with_deferred_notifications_should_update_state_immediately() {
+        //Given
+        let (ctx, _) = setup_deferred();
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(*ctx.store.state(), 1);
+        assert!(ctx.notified_values.borrow().is_empty());
+    }
+
+    #[test]
+    fn set_state_with_deferred_notifications_should_schedule_once_per_batch() {
+        //Given
+        let (ctx, scheduled) = setup_deferred();
+        //When
+        ctx.store.set_state(1);
+        ctx.store.set_state(2);
+        //Then
+        assert_eq!(scheduled.get(), 1);
+    }
+
+    #[test]
+    fn flush_with_batched_states_should_notify_once() {
+        //Given
+        let (ctx, _) = setup_deferred();
+        ctx.store.set_state(1);
+        ctx.store.set_state(2);
+        //When
+        ctx.store.flush();
+        ctx.store.flush();
+        //Then
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 2)]);
+    }
+
+    #[test]
+    fn flush_with_batch_flushed_should_start_new_batch() {
+        //Given
+        let (ctx, scheduled) = setup_deferred();
+        ctx.store.set_state(1);
+        ctx.store.flush();
+        //When
+        ctx.store.set_state(2);
+        ctx.store.flush();
+        //Then
+        assert_eq!(scheduled.get(), 2);
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1), (1, 2)]);
+    }
+
+    fn record_forced(store: &Store<i32>) -> Rc<RefCell<Vec<bool>>> {
+        let forced_values = Rc::new(RefCell::new(vec![]));
+        store.subscribe_forced({
+            let forced_values = forced_values.clone();
+            move |forced, _, _| {
+                forced_values.borrow_mut().push(forced);
+                true
+            }
+        });
+        forced_values
+    }
+
+    #[test]
+    fn flush_with_batch_ending_on_original_state_should_not_be_forced() {
+        //Given
+        let (ctx, _) = setup_deferred();
+        let forced_values = record_forced(&ctx.store);
+        ctx.store.set_state(1);
+        ctx.store.reset();
+        //When
+        ctx.store.flush();
+        //Then
+        assert_eq!(*forced_values.borrow(), &[false]);
+    }
+
+    #[test]
+    fn force_notify_with_unchanged_state_should_be_forced() {
+        //Given
+        let ctx = setup(0);
+        let forced_values = record_forced(&ctx.store);
+        //When
+        ctx.store.force_notify();
+        //Then
+        assert_eq!(*forced_values.borrow(), &[true]);
+    }
+
+    #[test]
+    fn set_state_with_new_state_should_increment_version() {
+        //Given
+        let ctx = setup(0);
+        //When
+        ctx.store.set_state(1);
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(ctx.store.version(), 2);
+    }
+
+    #[test]
+    fn set_state_with_cancelling_middleware_should_keep_version() {
+        //Given
+        let ctx = setup(0);
+        add_test_middleware(&ctx.store, Decision::Cancel);
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(ctx.store.version(), 0);
+    }
+
+    #[test]
+    fn undo_with_previous_state_should_increment_version() {
+        //Given
+        let ctx = setup_with_history(10);
+        ctx.store.set_state(1);
+        //When
+        ctx.store.undo();
+        //Then
+        assert_eq!(ctx.store.version(), 2);
+    }
+
+    #[test]
+    fn subscribe_versioned_with_callback_should_notify_current_version() {
+        //Given
+        let ctx = setup(0);
+        let notified_versions = Rc::new(RefCell::new(vec![]));
+        ctx.store.subscribe_versioned({
+            let notified_versions = notified_versions.clone();
+            move |version, prev, next| {
+                notified_versions.borrow_mut().push((version, *prev, *next));
+                true
+            }
+        });
+        //When
+        ctx.store.set_state(1);
+        ctx.store.set_state(2);
+        //Then
+        assert_eq!(*notified_versions.borrow(), &[(1, 0, 1), (2, 1, 2)]);
+    }
+
+    #[test]
+    fn subscribe_with_current_with_callback_should_notify_current_state() {
+        //Given
+        let ctx = setup(1);
+        let notified_values = Rc::new(RefCell::new(vec![]));
+        //When
+        ctx.store.subscribe_with_current({
+            let notified_values = notified_values.clone();
+            move |prev, next| {
+                notified_values.borrow_mut().push((*prev, *next));
+                true
+            }
+        });
+        ctx.store.set_state(2);
+        //Then
+        assert_eq!(*notified_values.borrow(), &[(1, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn subscribe_with_current_with_callback_returning_false_should_not_subscribe() {
+        //Given
+        let ctx = setup(1);
+        let sub_count = ctx.store.subscriber_count();
+        //When
+        ctx.store.subscribe_with_current(|_, _| false);
+        //Then
+        assert_eq!(ctx.store.subscriber_count(), sub_count);
+    }
+
+    #[test]
+    fn subscribe_once_with_callback_should_notify_next_change() {
+        //Given
+        let ctx = setup(0);
+        let notified_values = Rc::new(RefCell::new(vec![]));
+        ctx.store.subscribe_once({
+            let notified_values = notified_values.clone();
+            move |prev, next| notified_values.borrow_mut().push((*prev, *next))
+        });
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(*notified_values.borrow(), &[(0, 1)]);
+    }
+
+    #[test]
+    fn subscribe_once_with_callback_should_no_longer_notify_after_first_change() {
+        //Given
+        let ctx = setup(0);
+        let notified_values = Rc::new(RefCell::new(vec![]));
+        ctx.store.subscribe_once({
+            let notified_values = notified_values.clone();
+            move |prev, next| notified_values.borrow_mut().push((*prev, *next))
+        });
+        ctx.store.set_state(1);
+        //When
+        ctx.store.set_state(2);
+        //Then
+        assert_eq!(*notified_values.borrow(), &[(0, 1)]);
+    }
+
+    #[test]
+    fn subscribe_once_with_callback_should_drop_subscription_after_first_change() {
+        //Given
+        let ctx = setup(0);
+        let sub_count = ctx.store.subscriptions.borrow().len();
+        ctx.store.subscribe_once(|_, _| {});
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(ctx.store.subscriptions.borrow().len(), sub_count);
+    }
+
+    #[test]
+    fn subscribe_weak_with_owner_alive_should_notify_owner() {
+        //Given
+        let ctx = setup(0);
+        let owner = Rc::new(RefCell::new(vec![]));
+        ctx.store.subscribe_weak(&owner, |owner, prev, next| {
+            owner.borrow_mut().push((*prev, *next))
+        });
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(*owner.borrow(), &[(0, 1)]);
+    }
+
+    #[test]
+    fn subscribe_weak_with_owner_dropped_should_drop_subscription_on_next_change() {
+        //Given
+        let ctx = setup(0);
+        let sub_count = ctx.store.subscriber_count();
+        let owner = Rc::new(());
+        ctx.store.subscribe_weak(&owner, |_, _, _| {});
+        drop(owner);
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(ctx.store.subscriber_count(), sub_count);
+    }
+
+    #[test]
+    fn force_notify_with_unchanged_state_should_notify_current_state() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.set_state(1);
+        //When
+        ctx.store.force_notify();
+        //Then
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn force_notify_with_unchanged_state_should_update_previous_state() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.set_state(1);
+        //When
+        ctx.store.force_notify();
+        //Then
+        assert!(Rc::ptr_eq(&ctx.store.previous_state(), &ctx.store.state()));
+    }
+
+    #[test]
+    fn subscriber_count_with_subscription_dropped_should_not_count_dropped_subscription() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.subscribe(|_, _| false);
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(ctx.store.subscriber_count(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "testing")]
+    fn clear_subscriptions_with_subscriptions_should_no_longer_notify() {
+        //Given
+        let ctx = setup(0);
+        //When
+        ctx.store.clear_subscriptions();
+        ctx.store.set_state(1);
+        //Then
+        assert!(ctx.notified_values.borrow().is_empty());
+        assert_eq!(ctx.store.subscriber_count(), 0);
+        assert_eq!(ctx.store.stats().subscriptions_dropped, 1);
+    }
+
+    #[test]
+    fn with_capacity_hint_with_state_changed_should_keep_capacity() {
+        //Given
+        let ctx = setup_store(Store::with_capacity_hint(0, 16));
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert!(ctx.store.subscriptions.borrow().capacity() >= 16);
+    }
+
+    #[test]
+    fn map_into_with_store_changed_inside_should_give_value_of_current_state() {
+        //Given
+        let ctx = setup(1);
+        //When
+        let value = ctx.store.map_into(|state| {
+            ctx.store.set_state(state + 1);
+            *state
+        });
+        //Then
+        assert_eq!(value, 1);
+        assert_eq!(*ctx.store.state(), 2);
+    }
+
+    #[test]
+    fn set_state_with_subscription_added_while_notifying_should_notify_it_after_existing_ones() {
+        //Given
+        let store = Rc::new(Store::new(0));
+        let order = Rc::new(RefCell::new(vec![]));
+        let record = |name: &'static str| {
+            let order = order.clone();
+            move |_: &i32, _: &i32| {
+                order.borrow_mut().push(name);
+                true
+            }
+        };
+        store.subscribe({
+            let (weak_store, added) = (Rc::downgrade(&store), RefCell::new(Some(record("added"))));
+            let first = record("first");
+            move |prev, next| {
+                if let Some(added) = added.borrow_mut().take() {
+                    weak_store.upgrade().unwrap().subscribe(added);
+                }
+                first(prev, next)
+            }
+        });
+        store.subscribe(record("second"));
+        store.set_state(1);
+        order.borrow_mut().clear();
+        //When
+        store.set_state(2);
+        //Then
+        assert_eq!(*order.borrow(), &["first", "second", "added"]);
+    }
+
+    #[test]
+    fn map_into_should_transform_current_state_without_subscribing() {
+        //Given
+        let ctx = setup(1);
+        let sub_count = ctx.store.subscriber_count();
+        //When
+        let value = ctx.store.map_into(|state| state.to_string());
+        //Then
+        assert_eq!(value, "1");
+        assert_eq!(ctx.store.subscriber_count(), sub_count);
+    }
+
+    #[test]
+    fn stats_with_notified_subscriptions_should_count_notifications() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.subscribe(|_, _| true);
+        //When
+        ctx.store.set_state(1);
+        ctx.store.set_state(2);
+        //Then
+        assert_eq!(ctx.store.stats().notifications, 4);
+    }
+
+    #[test]
+    fn stats_with_subscriptions_added_should_count_added_subscriptions() {
+        //Given
+        let ctx = setup(0);
+        //When
+        ctx.store.subscribe(|_, _| true);
+        //Then
+        assert_eq!(ctx.store.stats().subscriptions_added, 2);
+    }
+
+    #[test]
+    fn stats_with_subscription_no_longer_active_should_count_dropped_subscriptions() {
+        //Given
+        let ctx = setup(0);
+        *ctx.is_sub_active.borrow_mut() = false;
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(ctx.store.stats().subscriptions_dropped, 1);
+    }
+
+    type Transitions = Rc<RefCell<Vec<(&'static str, i32, i32)>>>;
+
+    struct TestMiddleware {
+        decision: Decision,
+        transitions: Transitions,
+    }
+
+    impl Middleware<i32> for TestMiddleware {
+        fn before(&self, prev: &i32, next: &i32) -> Decision {
+            self.transitions.borrow_mut().push(("before", *prev, *next));
+            self.decision
+        }
+
+        fn notify(&self, prev: &i32, next: &i32) {
+            self.transitions.borrow_mut().push(("notify", *prev, *next));
+        }
+
+        fn after(&self, prev: &i32, next: &i32) {
+            self.transitions.borrow_mut().push(("after", *prev, *next));
+        }
+    }
+
+    fn add_test_middleware(store: &Store<i32>, decision: Decision) -> Transitions {
+        let transitions = Rc::new(RefCell::new(vec![]));
+        store.add_middleware(TestMiddleware {
+            decision,
+            transitions: transitions.clone(),
+        });
+        transitions
+    }
+
+    #[test]
+    fn set_state_with_middleware_should_run_before_and_after_transition() {
+        //Given
+        let ctx = setup(0);
+        let transitions = add_test_middleware(&ctx.store, Decision::Continue);
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(
+            *transitions.borrow(),
+            &[("before", 0, 1), ("notify", 0, 1), ("after", 0, 1)]
+        );
+    }
+
+    fn add_closure_middleware(store: &Store<i32>) -> Transitions {
+        let transitions: Transitions = Rc::new(RefCell::new(vec![]));
+        store.add_middleware({
+            let transitions = transitions.clone();
+            move |prev: &i32, next: &i32| transitions.borrow_mut().push(("notify", *prev, *next))
+        });
+        transitions
+    }
+
+    #[test]
+    fn set_state_with_closure_middleware_should_invoke_closure() {
+        //Given
+        let ctx = setup(0);
+        let transitions = add_closure_middleware(&ctx.store);
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(*transitions.borrow(), &[("notify", 0, 1)]);
+    }
+
+    #[test]
+    fn set_state_with_closure_middleware_should_invoke_closure_before_subscribers() {
+        //Given
+        let ctx = setup(0);
+        let transitions = add_closure_middleware(&ctx.store);
+        ctx.store.subscribe({
+            let transitions = transitions.clone();
+            move |prev, next| {
+                transitions.borrow_mut().push(("subscriber", *prev, *next));
+                false
+            }
+        });
+        //When
+        ctx.store.set_state(1);
+        ctx.store.set_state(2);
+        //Then
+        assert_eq!(
+            *transitions.borrow(),
+            &[("notify", 0, 1), ("subscriber", 0, 1), ("notify", 1, 2)]
+        );
+    }
+
+    #[test]
+    fn force_notify_with_closure_middleware_should_invoke_closure() {
+        //Given
+        let ctx = setup(1);
+        let transitions = add_closure_middleware(&ctx.store);
+        //When
+        ctx.store.force_notify();
+        //Then
+        assert_eq!(*transitions.borrow(), &[("notify", 1, 1)]);
+    }
+
+    #[test]
+    fn set_state_with_cancelling_middleware_should_not_update_state() {
+        //Given
+        let ctx = setup(0);
+        add_test_middleware(&ctx.store, Decision::Cancel);
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert_eq!(*ctx.store.state(), 0);
+    }
+
+    #[test]
+    fn set_state_with_cancelling_middleware_should_not_notify() {
+        //Given
+        let ctx = setup(0);
+        add_test_middleware(&ctx.store, Decision::Cancel);
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert!(ctx.notified_values.borrow().is_empty());
+    }
+
+    #[test]
+    fn set_state_with_cancelling_middleware_should_skip_following_middlewares() {
+        //Given
+        let ctx = setup(0);
+        add_test_middleware(&ctx.store, Decision::Cancel);
+        let transitions = add_test_middleware(&ctx.store, Decision::Continue);
+        //When
+        ctx.store.set_state(1);
+        //Then
+        assert!(transitions.borrow().is_empty());
+    }
+
+    fn setup_with_history(capacity: usize) -> TestContext<i32> {
+        setup_store(Store::with_history(0, capacity))
+    }
+
+    #[test]
+    fn undo_with_previous_state_should_restore_previous_state() {
+        //Given
+        let ctx = setup_with_history(10);
+        ctx.store.set_state(1);
+        ctx.store.set_state(2);
+        //When
+        ctx.store.undo();
+        //Then
+        assert_eq!(*ctx.store.state(), 1);
+    }
+
+    #[test]
+    fn undo_with_no_previous_state_should_keep_current_state() {
+        //Given
+        let ctx = setup_with_history(10);
+        ctx.store.set_state(1);
+        ctx.store.undo();
+        //When
+        let undone = ctx.store.undo();
+        //Then
+        assert!(!undone);
+        assert_eq!(*ctx.store.state(), 0);
+    }
+
+    #[test]
+    fn undo_without_history_should_keep_current_state() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.set_state(1);
+        //When
+        let undone = ctx.store.undo();
+        //Then
+        assert!(!undone);
+        assert_eq!(*ctx.store.state(), 1);
+    }
+
+    #[test]
+    fn undo_with_more_states_than_capacity_should_evict_oldest_states() {
+        //Given
+        let ctx = setup_with_history(2);
+        ctx.store.set_state(1);
+        ctx.store.set_state(2);
+        ctx.store.set_state(3);
+        //When
+        while ctx.store.undo() {}
+        //Then
+        assert_eq!(*ctx.store.state(), 1);
+    }
+
+    #[test]
+    fn history_with_committed_states_should_give_previous_states() {
+        //Given
+        let ctx = setup_with_history(10);
+        ctx.store.set_state(1);
+        ctx.store.set_state(2);
+        //When
+        let history = ctx.store.history();
+        //Then
+        assert_eq!(history.iter().map(|s| **s).collect::<Vec<_>>(), [0, 1]);
+    }
+
+    #[test]
+    fn history_with_undone_state_should_not_give_undone_state() {
+        //Given
+        let ctx = setup_with_history(10);
+        ctx.store.set_state(1);
+        ctx.store.set_state(2);
+        ctx.store.undo();
+        //When
+        let history = ctx.store.history();
+        //Then
+        assert_eq!(history.iter().map(|s| **s).collect::<Vec<_>>(), [0]);
+    }
+
+    #[test]
+    fn history_without_history_should_be_empty() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.set_state(1);
+        //When
+        let history = ctx.store.history();
+        //Then
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn redo_with_undone_state_should_restore_undone_state() {
+        //Given
+        let ctx = setup_with_history(10);
+        ctx.store.set_state(1);
+        ctx.store.undo();
+        //When
+        ctx.store.redo();
+        //Then
+        assert_eq!(*ctx.store.state(), 1);
+    }
+
+    #[test]
+    fn redo_with_new_state_after_undo_should_not_restore_undone_state() {
+        //Given
+        let ctx = setup_with_history(10);
+        ctx.store.set_state(1);
+        ctx.store.undo();
+        ctx.store.set_state(2);
+        //When
+        let redone = ctx.store.redo();
+        //Then
+        assert!(!redone);
+        assert!(!ctx.store.can_redo());
+        assert_eq!(*ctx.store.state(), 2);
+    }
+
+    #[test]
+    fn undo_with_previous_state_should_notify() {
+        //Given
+        let ctx = setup_with_history(10);
+        ctx.store.set_state(1);
+        //When
+        ctx.store.undo();
+        //Then
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn set_state_rc_with_new_state_should_install_given_state() {
+        //Given
+        let ctx = setup(0);
+        let state = Rc::new(1);
+        //When
+        ctx.store.set_state_rc(state.clone());
+        //Then
+        assert!(Rc::ptr_eq(&ctx.store.state(), &state));
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1)]);
+    }
+
+    #[test]
+    fn set_state_rc_with_current_state_should_not_notify() {
+        //Given
+        let ctx = setup(0);
+        ctx.store.set_state(1);
+        let previous = ctx.store.previous_state();
+        //When
+        ctx.store.set_state_rc(ctx.store.state());
+        //Then
+        assert!(Rc::ptr_eq(&ctx.store.previous_state(), &previous));
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 1)]);
+    }
+
+    #[test]
+    fn subscribe_with_callback_should_add_callback_to_subscriptions() {
+        //Given
+        let ctx = setup(0);
+        let sub_count = ctx.store.subscriptions.borrow().len();
+        //When
+        ctx.store.subscribe(|_, _| false);
+        //Then
+        assert_eq!(ctx.store.subscriptions.borrow().len(), sub_count + 1);
+    }
+
+    #[test]
+    fn fmt_with_debug_state_should_print_state_and_subscriptions() {
+        //Given
+        let ctx = setup(1);
+        //When
+        let output = format!("{:?}", ctx.store);
+        //Then
+        assert_eq!(output, "Store { state: 1, subscriptions: 1 }");
+    }
+
+    #[test]
+    fn fmt_during_notification_should_not_panic() {
+        //Given
+        let store = Rc::new(Store::new(0));
+        let output = Rc::new(RefCell::new(String::new()));
+        store.subscribe({
+            let store = Rc::downgrade(&store);
+            let output = output.clone();
+            move |_, _| {
+                *output.borrow_mut() = format!("{:?}", store.upgrade().unwrap());
+                true
+            }
+        });
+        //When
+        store.set_state(1);
+        //Then
+        assert_eq!(*output.borrow(), "Store { state: 1, subscriptions: 0 }");
+    }
+
+    #[test]
+    fn derived_with_derived_value_changed_should_notify_derived_subscribers() {
+        //Given
+        let store = Store::new(1);
+        let derived = store.derived(|value| value % 2 == 0);
+        let notified = Rc::new(Cell::new(0));
+        derived.subscribe({
+            let notified = notified.clone();
+            move |_, _| {
+                notified.set(notified.get() + 1);
+                true
+            }
+        });
+        //When
+        store.set_state(3);
+        store.set_state(4);
+        //Then
+        assert!(*derived.state());
+        assert_eq!(notified.get(), 1);
+    }
+
+    #[test]
+    fn derived_with_derived_store_dropped_should_unsubscribe_on_next_change() {
+        //Given
+        let store = Store::new(1);
+        let derived = store.derived(|value| *value);
+        drop(derived);
+        //When
+        store.set_state(2);
+        //Then
+        assert_eq!(store.subscriber_count(), 0);
+    }
+
+    #[test]
+    fn derived_with_chained_derived_stores_should_propagate_changes() {
+        //Given
+        let store = Store::new(vec![1, 2]);
+        let sum = store.derived(|items| items.iter().sum::<i32>());
+        let is_large = sum.derived(|sum| *sum > 5);
+        //When
+        store.set_state(vec![1, 2, 3]);
+        //Then
+        assert_eq!(*sum.state(), 6);
+        assert!(*is_large.state());
     }
 }
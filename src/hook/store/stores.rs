@@ -0,0 +1,103 @@
+use super::{not_registered, StoreContext, StoreSlot, UseStoreHandle};
+use std::{any::type_name, marker::PhantomData};
+use yew::{
+    functional::{Hook, HookContext},
+    use_context, use_force_update, use_state,
+};
+
+/// Tuple of store state types, obtained together with `use_stores`.
+pub trait Stores: 'static {
+    /// Tuple of the handles to each store.
+    type Handles;
+
+    #[doc(hidden)]
+    fn run(ctx: &mut HookContext) -> Self::Handles;
+}
+
+macro_rules! impl_stores {
+    ($(($state:ident, $index:tt)),+) => {
+        impl<$($state: 'static),+> Stores for ($($state,)+) {
+            type Handles = ($(UseStoreHandle<$state>,)+);
+
+            fn run(ctx: &mut HookContext) -> Self::Handles {
+                let contexts = ($(use_context::<StoreContext<$state>>().run(ctx),)+);
+                let renderer = use_force_update().run(ctx);
+                let hook = use_state(|| ($(StoreSlot::<$state>::default(),)+)).run(ctx);
+                ($(
+                    hook.$index
+                        .bind(contexts.$index, &renderer)
+                        .unwrap_or_else(|| panic!("{}", missing::<$state>($index))),
+                )+)
+            }
+        }
+    };
+}
+
+impl_stores!((A, 0));
+impl_stores!((A, 0), (B, 1));
+impl_stores!((A, 0), (B, 1), (C, 2));
+impl_stores!((A, 0), (B, 1), (C, 2), (D, 3));
+
+/// Message of the panic raised when the store of the tuple element at `index` isn't registered.
+fn missing<T>(index: usize) -> String {
+    format!(
+        "{} It is the element {} of the tuple given to `use_stores`.",
+        not_registered("use_stores", "StoreContext", type_name::<T>()),
+        index
+    )
+}
+
+/// Obtain a handle to each store of the tuple `S` of state types, sharing the hooks bookkeeping between them.
+/// Each handle exposes the whole `UseStoreHandle` API, as with `use_store`.
+/// ```rust
+/// use yew::prelude::*;
+/// use yewv::use_stores;
+///
+/// struct User {
+///     name: String
+/// }
+///
+/// struct Cart {
+///     items: Vec<String>
+/// }
+///
+/// #[function_component]
+/// fn Test() -> Html {
+///     let (user, cart) = use_stores::<(User, Cart)>();
+///     let name = user.map_ref(|state| &state.name);
+///     let count = cart.map(|state| state.items.len());
+///     
+///     html!{ format!("{} {}", name, count) }
+/// }
+/// ```
+pub fn use_stores<S: Stores>() -> impl Hook<Output = S::Handles> {
+    UseStores::<S>(PhantomData)
+}
+
+struct UseStores<S>(PhantomData<fn() -> S>);
+
+impl<S: Stores> Hook for UseStores<S> {
+    type Output = S::Handles;
+
+    fn run(self, ctx: &mut HookContext) -> Self::Output {
+        S::run(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Cart;
+
+    #[test]
+    fn missing_should_name_tuple_element() {
+        //Given
+        let index = 1;
+        //When
+        let message = missing::<Cart>(index);
+        //Then
+        assert!(message.starts_with("`use_stores` found no `StoreContext<"));
+        assert!(message.ends_with("It is the element 1 of the tuple given to `use_stores`."));
+    }
+}
@@ -0,0 +1,109 @@
+use super::Store;
+use futures_channel::mpsc;
+use futures_core::Stream;
+use std::rc::Rc;
+
+impl<T: 'static> Store<T> {
+    /// Give a stream yielding every next state of the store.
+    /// Once the stream is dropped, the subscription feeding it is dropped on the next change.
+    /// ```rust
+    /// use futures::{executor::block_on, StreamExt};
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(0);
+    /// let changes = store.changes();
+    /// store.set_state(1);
+    /// store.set_state(2);
+    /// let states: Vec<_> = block_on(changes.take(2).map(|s| *s).collect());
+    /// assert_eq!(states, [1, 2]);
+    /// ```
+    pub fn changes(&self) -> impl Stream<Item = Rc<T>> {
+        let (sender, receiver) = mpsc::unbounded();
+        self.subscribe_rc(move |_, next| sender.unbounded_send(next.clone()).is_ok());
+        receiver
+    }
+
+    /// Give a stream yielding the value selected from the next state of the store, only when it changed.
+    /// Once the stream is dropped, the subscription feeding it is dropped on the next change.
+    /// ```rust
+    /// use futures::{executor::block_on, StreamExt};
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new((0, 0));
+    /// let changes = store.changes_map(|state| state.0);
+    /// store.set_state((1, 0));
+    /// store.set_state((1, 1));
+    /// store.set_state((2, 1));
+    /// let values: Vec<_> = block_on(changes.take(2).collect());
+    /// assert_eq!(values, [1, 2]);
+    /// ```
+    pub fn changes_map<M: PartialEq + 'static>(
+        &self,
+        selector: impl Fn(&T) -> M + 'static,
+    ) -> impl Stream<Item = M> {
+        let (sender, receiver) = mpsc::unbounded();
+        self.subscribe(move |prev, next| {
+            let value = selector(next);
+            if value == selector(prev) {
+                return !sender.is_closed();
+            }
+            sender.unbounded_send(value).is_ok()
+        });
+        receiver
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{executor::block_on, StreamExt};
+
+    #[test]
+    fn changes_with_new_states_should_yield_states() {
+        //Given
+        let store = Store::new(0);
+        let changes = store.changes();
+        //When
+        for i in 1..=3 {
+            store.set_state(i);
+        }
+        //Then
+        let states: Vec<_> = block_on(changes.take(3).collect());
+        assert_eq!(states, [Rc::new(1), Rc::new(2), Rc::new(3)]);
+    }
+
+    #[test]
+    fn changes_with_stream_dropped_should_drop_subscription() {
+        //Given
+        let store = Store::new(0);
+        drop(store.changes());
+        //When
+        store.set_state(1);
+        //Then
+        assert_eq!(store.subscriber_count(), 0);
+    }
+
+    #[test]
+    fn changes_map_with_selected_value_unchanged_should_not_yield() {
+        //Given
+        let store = Store::new((0, 0));
+        let changes = store.changes_map(|state| state.0);
+        //When
+        store.set_state((0, 1));
+        store.set_state((1, 1));
+        //Then
+        let values: Vec<_> = block_on(changes.take(1).collect());
+        assert_eq!(values, [1]);
+    }
+
+    #[test]
+    fn changes_map_with_stream_dropped_should_drop_subscription() {
+        //Given
+        let store = Store::new((0, 0));
+        drop(store.changes_map(|state| state.0));
+        //When
+        store.set_state((0, 1));
+        //Then
+        assert_eq!(store.subscriber_count(), 0);
+    }
+}
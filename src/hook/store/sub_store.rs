@@ -0,0 +1,163 @@
+use super::StoreContext;
+use std::{cell::Ref, rc::Rc};
+
+type Getter<T, U> = Rc<dyn Fn(&T) -> &U>;
+type Setter<T, U> = Rc<dyn Fn(&mut T, U)>;
+
+/// Handle projecting a part `U` of a parent store state `T`.
+/// Changes made through the handle are written back into the parent store, whose subscribers are notified.
+pub struct SubStore<T, U> {
+    context: StoreContext<T>,
+    get: Getter<T, U>,
+    set: Setter<T, U>,
+}
+
+impl<T: 'static, U: 'static> SubStore<T, U> {
+    /// Give a reference to the current projected state.
+    /// The reference must be released before changing the store state.
+    pub fn state(&self) -> Ref<'_, U> {
+        Ref::map(self.context.state_ref(), |s| (self.get)(s))
+    }
+
+    /// Subscribe to the changes made to the parent store, with the previous and next projected states.
+    /// Returning `false` drops the subscription, as with `Store::subscribe`.
+    pub fn subscribe(&self, callback: impl Fn(&U, &U) -> bool + 'static) {
+        let get = self.get.clone();
+        self.context
+            .subscribe(move |prev, next| callback(get(prev), get(next)));
+    }
+}
+
+impl<T: Clone + 'static, U> SubStore<T, U> {
+    /// Write `new_state` into a copy of the parent store state, then set it as the parent store next state.
+    pub fn set_state(&self, new_state: U) {
+        let mut next = (*self.context.state()).clone();
+        (self.set)(&mut next, new_state);
+        self.context.set_state(next);
+    }
+}
+
+impl<T> StoreContext<T> {
+    /// Creates a `SubStore` reading its state with `get` and writing it back into this store with `set`.
+    /// The sub-store can be handed to a subtree which should only know about a part of the state.
+    /// ```rust
+    /// use yewv::StoreContext;
+    ///
+    /// #[derive(Clone)]
+    /// struct AppState {
+    ///     user: String,
+    ///     count: i32,
+    /// }
+    ///
+    /// let store = StoreContext::new(AppState { user: "Alice".to_string(), count: 0 });
+    /// let count = store.project(|state| &state.count, |state, count| state.count = count);
+    /// count.set_state(1);
+    /// assert_eq!(*count.state(), 1);
+    /// assert_eq!(store.state().count, 1);
+    /// ```
+    pub fn project<U>(
+        &self,
+        get: impl Fn(&T) -> &U + 'static,
+        set: impl Fn(&mut T, U) + 'static,
+    ) -> SubStore<T, U> {
+        SubStore {
+            context: self.clone(),
+            get: Rc::new(get),
+            set: Rc::new(set),
+        }
+    }
+}
+
+impl<T, U> PartialEq for SubStore<T, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.context == other.context && Rc::ptr_eq(&self.get, &other.get)
+    }
+}
+
+impl<T, U> Clone for SubStore<T, U> {
+    fn clone(&self) -> Self {
+        Self {
+            context: self.context.clone(),
+            get: self.get.clone(),
+            set: self.set.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    struct TestContext {
+        parent: StoreContext<(i32, i32)>,
+        sub_store: SubStore<(i32, i32), i32>,
+        notified_values: Rc<RefCell<Vec<(i32, i32)>>>,
+    }
+
+    fn setup() -> TestContext {
+        let parent = StoreContext::new((0, 0));
+        let sub_store = parent.project(|state| &state.1, |state, value| state.1 = value);
+        let notified_values = Rc::new(RefCell::new(vec![]));
+        parent.subscribe({
+            let notified_values = notified_values.clone();
+            move |_, next| {
+                notified_values.borrow_mut().push(*next);
+                true
+            }
+        });
+        TestContext {
+            parent,
+            sub_store,
+            notified_values,
+        }
+    }
+
+    #[test]
+    fn state_with_parent_state_changed_should_give_projected_state() {
+        //Given
+        let ctx = setup();
+        //When
+        ctx.parent.set_state((1, 2));
+        //Then
+        assert_eq!(*ctx.sub_store.state(), 2);
+    }
+
+    #[test]
+    fn set_state_with_new_state_should_write_into_parent_state() {
+        //Given
+        let ctx = setup();
+        //When
+        ctx.sub_store.set_state(3);
+        //Then
+        assert_eq!(*ctx.parent.state(), (0, 3));
+    }
+
+    #[test]
+    fn set_state_with_new_state_should_notify_parent_subscribers() {
+        //Given
+        let ctx = setup();
+        //When
+        ctx.sub_store.set_state(3);
+        //Then
+        assert_eq!(*ctx.notified_values.borrow(), &[(0, 3)]);
+    }
+
+    #[test]
+    fn subscribe_with_parent_state_changed_should_notify_projected_states() {
+        //Given
+        let ctx = setup();
+        let projected_values = Rc::new(RefCell::new(vec![]));
+        ctx.sub_store.subscribe({
+            let projected_values = projected_values.clone();
+            move |prev, next| {
+                projected_values.borrow_mut().push((*prev, *next));
+                true
+            }
+        });
+        //When
+        ctx.parent.set_state((1, 2));
+        //Then
+        assert_eq!(*projected_values.borrow(), &[(0, 2)]);
+    }
+}
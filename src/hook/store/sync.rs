@@ -0,0 +1,108 @@
+use super::StoreContext;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{cell::Cell, rc::Rc};
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+use web_sys::{BroadcastChannel, MessageEvent};
+
+/// Message exchanged between the stores synced on a channel.
+#[derive(Serialize, Deserialize)]
+struct SyncMessage<S> {
+    /// Identifier of the store which posted the message.
+    origin: u64,
+    /// State of the store, or `None` to request the state of the other stores.
+    state: Option<S>,
+}
+
+/// Channel kept alive for as long as the synced store is.
+struct SyncChannel {
+    channel: BroadcastChannel,
+    _onmessage: Closure<dyn FnMut(MessageEvent)>,
+}
+
+impl Drop for SyncChannel {
+    fn drop(&mut self) {
+        self.channel.set_onmessage(None);
+        self.channel.close();
+    }
+}
+
+fn post<S: Serialize>(channel: &BroadcastChannel, origin: u64, state: Option<S>) {
+    if let Ok(json) = serde_json::to_string(&SyncMessage { origin, state }) {
+        let _ = channel.post_message(&JsValue::from_str(&json));
+    }
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> StoreContext<T> {
+    /// Creates a new `StoreContext` synchronized with every store synced on the `BroadcastChannel` named `channel_name`,
+    /// such as the same store opened in other tabs.
+    ///
+    /// Every state change is posted on the channel and states received from the channel are set like any other change.
+    /// On creation, the store requests the state of the stores already synced, falling back to `initial` until one answers.
+    /// Concurrent changes are resolved by keeping the last one received.
+    ///
+    /// When `BroadcastChannel` is unavailable, the store is kept in memory only.
+    /// ```rust,no_run
+    /// use serde::{Deserialize, Serialize};
+    /// use yewv::StoreContext;
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct AppState {
+    ///     count: i32,
+    /// }
+    ///
+    /// let store = StoreContext::synced("app", AppState { count: 0 });
+    /// ```
+    pub fn synced(channel_name: &str, initial: T) -> Self {
+        let context = Self::new(initial);
+        let Ok(channel) = BroadcastChannel::new(channel_name) else {
+            return context;
+        };
+        let origin = (js_sys::Math::random() * u64::MAX as f64) as u64;
+        // Set while a received state is applied, so it isn't posted back to the channel.
+        let receiving = Rc::new(Cell::new(false));
+        let onmessage = Closure::<dyn FnMut(MessageEvent)>::new({
+            let store = Rc::downgrade(context.store_ref());
+            let channel = channel.clone();
+            let receiving = receiving.clone();
+            move |event: MessageEvent| {
+                let Some(store) = store.upgrade() else {
+                    return;
+                };
+                let message = event
+                    .data()
+                    .as_string()
+                    .and_then(|json| serde_json::from_str::<SyncMessage<T>>(&json).ok());
+                match message {
+                    Some(message) if message.origin != origin => match message.state {
+                        Some(state) => {
+                            receiving.set(true);
+                            store.set_state(state);
+                            receiving.set(false);
+                        }
+                        None => post(&channel, origin, Some(&**store.state_ref())),
+                    },
+                    _ => {}
+                }
+            }
+        });
+        channel.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+        post::<T>(&channel, origin, None);
+        let sync = SyncChannel {
+            channel,
+            _onmessage: onmessage,
+        };
+        context.subscribe(move |_, next| {
+            if !receiving.get() {
+                post(&sync.channel, origin, Some(next));
+            }
+            true
+        });
+        context
+    }
+
+    /// Creates a new `StoreContext` synchronized across tabs on the `BroadcastChannel` named `channel`.
+    /// Same as `StoreContext::synced`.
+    pub fn new_synced(channel: &str, initial: T) -> Self {
+        Self::synced(channel, initial)
+    }
+}
@@ -0,0 +1,198 @@
+//! Thread-safe store, for logic running outside of the main thread such as in web workers.
+//!
+//! `SyncStore` offers the same `state`, `set_state` and `subscribe` surface as `Store`,
+//! so the same code can run in both contexts. It comes with a few trade-offs:
+//! - Every read and write takes a lock, which `Store` avoids on the single-threaded main path.
+//! - The state and subscriptions must be `Send + Sync`, and states are shared through `Arc`.
+//! - Concurrent changes are all applied, but their notifications may interleave across threads.
+//!
+//! `SyncStore` is not meant to be provided to components, which should keep using `StoreContext`.
+use std::{
+    fmt::{self, Debug},
+    sync::{Arc, Mutex, MutexGuard, RwLock},
+};
+
+type SyncSubscription<T> = Arc<dyn Fn(&T, &T) -> bool + Send + Sync>;
+
+/// Store with subscription capability, which can be shared between threads.
+pub struct SyncStore<T> {
+    state: RwLock<Arc<T>>,
+    subscriptions: Mutex<Vec<SyncSubscription<T>>>,
+}
+
+impl<T: Send + Sync> SyncStore<T> {
+    /// Create a new instance of a thread-safe store with the given state as initial state.
+    /// ```rust
+    /// use yewv::SyncStore;
+    ///
+    /// let store = SyncStore::new(0);
+    /// assert_eq!(*store.state(), 0);
+    /// ```
+    pub fn new(initial_state: T) -> Self {
+        Self {
+            state: RwLock::new(Arc::new(initial_state)),
+            subscriptions: Mutex::new(vec![]),
+        }
+    }
+
+    /// Give the current state of the store.
+    pub fn state(&self) -> Arc<T> {
+        self.state.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Set store next state and notify subscribers.
+    /// ```rust
+    /// use std::{sync::Arc, thread};
+    /// use yewv::SyncStore;
+    ///
+    /// let store = Arc::new(SyncStore::new(0));
+    /// let worker = store.clone();
+    /// thread::spawn(move || worker.set_state(1)).join().unwrap();
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn set_state(&self, new_state: T) {
+        let next = Arc::new(new_state);
+        let prev = {
+            let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+            std::mem::replace(&mut *state, next.clone())
+        };
+        self.notify(&prev, &next);
+    }
+
+    /// Subscribe to changes made to the store state.
+    /// When the `callback` returns `false` the subscription will be dropped.
+    /// ```rust
+    /// use yewv::SyncStore;
+    ///
+    /// let store = SyncStore::new(0);
+    /// store.subscribe(|prev_state, current_state| {
+    ///     /* Put your own subscription logic. */
+    ///     true // Should be the condition for unsubscription.
+    /// });
+    /// ```
+    pub fn subscribe(&self, callback: impl Fn(&T, &T) -> bool + Send + Sync + 'static) {
+        self.lock_subscriptions().push(Arc::new(callback));
+    }
+
+    /// Give the number of subscriptions currently registered.
+    pub fn subscriber_count(&self) -> usize {
+        self.lock_subscriptions().len()
+    }
+
+    fn lock_subscriptions(&self) -> MutexGuard<'_, Vec<SyncSubscription<T>>> {
+        self.subscriptions.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn notify(&self, prev: &T, next: &T) {
+        // Subscriptions are notified outside of the lock, so they can subscribe or set the state themselves,
+        // and so changes made from other threads meanwhile notify every subscription as well.
+        let subscriptions = self.lock_subscriptions().clone();
+        let dropped: Vec<_> = subscriptions
+            .into_iter()
+            .filter(|s| !s(prev, next))
+            .collect();
+        if !dropped.is_empty() {
+            self.lock_subscriptions()
+                .retain(|s| !dropped.iter().any(|d| Arc::ptr_eq(s, d)));
+        }
+    }
+}
+
+impl<T: Debug> Debug for SyncStore<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("SyncStore");
+        match self.state.try_read() {
+            Ok(state) => debug.field("state", &state),
+            Err(_) => debug.field("state", &format_args!("<locked>")),
+        };
+        match self.subscriptions.try_lock() {
+            Ok(subscriptions) => debug.field("subscriptions", &subscriptions.len()),
+            Err(_) => debug.field("subscriptions", &format_args!("<locked>")),
+        };
+        debug.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{sync::atomic::AtomicUsize, sync::atomic::Ordering, thread};
+
+    #[test]
+    fn set_state_with_new_state_should_notify_subscribers() {
+        //Given
+        let store = SyncStore::new(0);
+        let seen = Arc::new(Mutex::new(vec![]));
+        let sink = seen.clone();
+        store.subscribe(move |prev, next| {
+            sink.lock().unwrap().push((*prev, *next));
+            true
+        });
+        //When
+        store.set_state(1);
+        //Then
+        assert_eq!(*seen.lock().unwrap(), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn set_state_with_subscription_returning_false_should_drop_subscription() {
+        //Given
+        let store = SyncStore::new(0);
+        store.subscribe(|_, _| false);
+        //When
+        store.set_state(1);
+        //Then
+        assert_eq!(store.subscriber_count(), 0);
+    }
+
+    #[test]
+    fn set_state_with_subscription_subscribing_should_keep_both_subscriptions() {
+        //Given
+        let store = Arc::new(SyncStore::new(0));
+        let inner = store.clone();
+        store.subscribe(move |_, _| {
+            inner.subscribe(|_, _| true);
+            true
+        });
+        //When
+        store.set_state(1);
+        //Then
+        assert_eq!(store.subscriber_count(), 2);
+    }
+
+    #[test]
+    fn set_state_from_several_threads_should_notify_every_change() {
+        //Given
+        let store = Arc::new(SyncStore::new(0));
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = count.clone();
+        store.subscribe(move |_, _| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            true
+        });
+        //When
+        let workers: Vec<_> = (1..=4)
+            .map(|i| {
+                let store = store.clone();
+                thread::spawn(move || store.set_state(i))
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+        //Then
+        assert_eq!(count.load(Ordering::SeqCst), 4);
+        assert!((1..=4).contains(&*store.state()));
+    }
+
+    #[test]
+    fn debug_should_show_state_and_subscription_count() {
+        //Given
+        let store = SyncStore::new(1);
+        store.subscribe(|_, _| true);
+        //When
+        let debug = format!("{:?}", store);
+        //Then
+        assert_eq!(debug, "SyncStore { state: 1, subscriptions: 1 }");
+    }
+}
@@ -0,0 +1,186 @@
+use gloo_timers::callback::Timeout;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+
+/// Whether a throttled subscription renders the first change of each interval, the last one, or both.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThrottleMode {
+    /// Render the first change right away, ignoring the changes made until the interval elapsed.
+    Leading,
+    /// Render the last change once the interval elapsed.
+    Trailing,
+    /// Render the first change right away, and the last change once the interval elapsed.
+    Both,
+}
+
+impl ThrottleMode {
+    fn is_leading(self) -> bool {
+        self != Self::Trailing
+    }
+
+    fn is_trailing(self) -> bool {
+        self != Self::Leading
+    }
+}
+
+/// State of a hook rendering its value on a timer, with the value rendered, the last value mapped and the
+/// pending timer. The timer is cancelled when the state is dropped, such as when the component is unmounted.
+pub(crate) struct Timed<M> {
+    rendered: RefCell<Rc<M>>,
+    latest: RefCell<Rc<M>>,
+    timeout: RefCell<Option<Timeout>>,
+    is_pending: Cell<bool>,
+}
+
+impl<M: PartialEq + 'static> Timed<M> {
+    pub(crate) fn new(value: M) -> Self {
+        let value = Rc::new(value);
+        Self {
+            rendered: RefCell::new(value.clone()),
+            latest: RefCell::new(value),
+            timeout: RefCell::new(None),
+            is_pending: Cell::new(false),
+        }
+    }
+
+    /// Give the value to render.
+    pub(crate) fn rendered(&self) -> Rc<M> {
+        self.rendered.borrow().clone()
+    }
+
+    /// Record the `next` value mapped, giving `false` when it did not change.
+    fn set_latest(&self, next: M) -> bool {
+        if **self.latest.borrow() == next {
+            return false;
+        }
+        *self.latest.borrow_mut() = Rc::new(next);
+        true
+    }
+
+    /// Render the last value mapped, when it differs from the value rendered.
+    fn flush(&self, renderer: &dyn Fn()) {
+        let latest = self.latest.borrow().clone();
+        if *latest != **self.rendered.borrow() {
+            *self.rendered.borrow_mut() = latest;
+            renderer();
+        }
+    }
+
+    /// Start a timer calling `elapsed` after `duration`, cancelling the pending one.
+    fn start_timer(self: &Rc<Self>, duration: Duration, elapsed: impl FnOnce(&Self) + 'static) {
+        let millis = duration.as_millis().try_into().unwrap_or(u32::MAX);
+        let slot = Rc::downgrade(self);
+        self.is_pending.set(true);
+        *self.timeout.borrow_mut() = Some(Timeout::new(millis, move || {
+            if let Some(slot) = slot.upgrade() {
+                slot.is_pending.set(false);
+                elapsed(&slot);
+            }
+        }));
+    }
+
+    /// Record the `next` value and render it once no other change was made for `duration`.
+    pub(crate) fn debounce(self: &Rc<Self>, next: M, duration: Duration, renderer: &Rc<dyn Fn()>) {
+        if self.set_latest(next) {
+            let renderer = renderer.clone();
+            self.start_timer(duration, move |slot| slot.flush(&*renderer));
+        }
+    }
+
+    /// Record the `next` value and render at most once per `interval`, according to the `mode`.
+    pub(crate) fn throttle(
+        self: &Rc<Self>,
+        next: M,
+        interval: Duration,
+        mode: ThrottleMode,
+        renderer: &Rc<dyn Fn()>,
+    ) {
+        // Values changed during the interval are only recorded when the trailing edge will render them.
+        if self.is_pending.get() {
+            if mode.is_trailing() {
+                self.set_latest(next);
+            }
+            return;
+        }
+        if !self.set_latest(next) {
+            return;
+        }
+        let trailing = renderer.clone();
+        self.start_timer(interval, move |slot| {
+            if mode.is_trailing() {
+                slot.flush(&*trailing);
+            }
+        });
+        if mode.is_leading() {
+            self.flush(&**renderer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_latest_with_same_value_should_not_change() {
+        //Given
+        let timed = Timed::new(1);
+        //When
+        let changed = timed.set_latest(1);
+        //Then
+        assert!(!changed);
+    }
+
+    #[test]
+    fn flush_with_latest_value_changed_should_render_latest_value() {
+        //Given
+        let timed = Timed::new(1);
+        let render_count = Cell::new(0);
+        timed.set_latest(2);
+        //When
+        timed.flush(&|| render_count.set(render_count.get() + 1));
+        //Then
+        assert_eq!(*timed.rendered(), 2);
+        assert_eq!(render_count.get(), 1);
+    }
+
+    #[test]
+    fn flush_with_latest_value_rendered_should_not_render() {
+        //Given
+        let timed = Timed::new(1);
+        let render_count = Cell::new(0);
+        //When
+        timed.flush(&|| render_count.set(render_count.get() + 1));
+        //Then
+        assert_eq!(render_count.get(), 0);
+    }
+
+    #[test]
+    fn throttle_with_leading_while_pending_should_not_record_dropped_value() {
+        //Given
+        let timed = Rc::new(Timed::new(1));
+        timed.is_pending.set(true);
+        let renderer: Rc<dyn Fn()> = Rc::new(|| ());
+        //When
+        timed.throttle(2, Duration::ZERO, ThrottleMode::Leading, &renderer);
+        //Then
+        assert_eq!(**timed.latest.borrow(), 1);
+    }
+
+    #[test]
+    fn throttle_modes_should_tell_leading_and_trailing_edges() {
+        //Given
+        let modes = [
+            ThrottleMode::Leading,
+            ThrottleMode::Trailing,
+            ThrottleMode::Both,
+        ];
+        //When
+        let edges = modes.map(|mode| (mode.is_leading(), mode.is_trailing()));
+        //Then
+        assert_eq!(edges, [(true, false), (false, true), (true, true)]);
+    }
+}
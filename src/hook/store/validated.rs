@@ -0,0 +1,282 @@
+use super::{Decision, Middleware, StoreContext};
+use std::{
+    cell::RefCell,
+    fmt::{self, Debug},
+    ops::Deref,
+    rc::Rc,
+};
+
+type Validator<T, E> = Box<dyn Fn(&T) -> Result<(), E>>;
+
+/// Validation error of the last rejected state, until taken back by `try_set_state`.
+type Rejection<E> = Rc<RefCell<Option<E>>>;
+
+/// Middleware cancelling transitions to states rejected by the validator.
+struct Validation<T, E> {
+    validator: Validator<T, E>,
+    rejection: Rejection<E>,
+}
+
+impl<T, E> Middleware<T> for Validation<T, E> {
+    fn before(&self, _prev: &T, next: &T) -> Decision {
+        match (self.validator)(next) {
+            Ok(()) => Decision::Continue,
+            Err(e) => {
+                *self.rejection.borrow_mut() = Some(e);
+                Decision::Cancel
+            }
+        }
+    }
+}
+
+/// Store context whose states are checked by a validator before being set.
+/// Invalid states set with `set_state` are ignored, `try_set_state` and `try_update` return the validation error instead.
+///
+/// The inner context can be registered with `ContextProvider<StoreContext<T>>` and used with `use_store::<T>()`,
+/// every change made to it is validated as well.
+pub struct ValidatedStoreContext<T, E> {
+    context: StoreContext<T>,
+    rejection: Rejection<E>,
+}
+
+impl<T: 'static, E: 'static> ValidatedStoreContext<T, E> {
+    /// Give the validated store context, to register it with `ContextProvider<StoreContext<T>>`.
+    pub fn context(&self) -> &StoreContext<T> {
+        &self.context
+    }
+
+    /// Set store next state, unless the validator rejects it.
+    /// On error, the store is left untouched, subscribers are not notified and the validation error is returned.
+    /// Otherwise, return whether the state changed, which it does not when another middleware cancels the change.
+    pub fn try_set_state(&self, new_state: T) -> Result<bool, E> {
+        // The state is validated once, by the middleware, which keeps the error of a rejected state.
+        self.rejection.borrow_mut().take();
+        let changed = self.context.commit(Rc::new(new_state));
+        match self.rejection.borrow_mut().take() {
+            Some(e) => Err(e),
+            None => Ok(changed),
+        }
+    }
+
+    /// Compute store next state from the current state, unless `f` fails or the validator rejects the result.
+    /// On error, the store is left untouched, subscribers are not notified and the error is returned.
+    /// Otherwise, return whether the state changed, as with `try_set_state`.
+    pub fn try_update(&self, f: impl FnOnce(&T) -> Result<T, E>) -> Result<bool, E> {
+        let next = f(&self.context.state())?;
+        self.try_set_state(next)
+    }
+}
+
+impl<T: 'static> StoreContext<T> {
+    /// Creates a new `StoreContext` with the given `initial_state`, checking every next state with `validator`.
+    /// The initial state is trusted and not validated.
+    /// ```rust
+    /// use yewv::StoreContext;
+    ///
+    /// let store = StoreContext::new_validated(0, |count: &i32| {
+    ///     if *count < 0 {
+    ///         return Err("Count can't be negative");
+    ///     }
+    ///     Ok(())
+    /// });
+    /// assert_eq!(store.try_set_state(-1), Err("Count can't be negative"));
+    /// store.set_state(-1);
+    /// assert_eq!(*store.state(), 0);
+    /// assert_eq!(store.try_update(|count| Ok(count + 1)), Ok(true));
+    /// assert_eq!(*store.state(), 1);
+    /// ```
+    pub fn new_validated<E: 'static>(
+        initial_state: T,
+        validator: impl Fn(&T) -> Result<(), E> + 'static,
+    ) -> ValidatedStoreContext<T, E> {
+        let rejection: Rejection<E> = Rc::new(RefCell::new(None));
+        let context = Self::new(initial_state);
+        context.add_middleware(Validation {
+            validator: Box::new(validator),
+            rejection: rejection.clone(),
+        });
+        ValidatedStoreContext { context, rejection }
+    }
+}
+
+impl<T, E> Deref for ValidatedStoreContext<T, E> {
+    type Target = StoreContext<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.context
+    }
+}
+
+impl<T, E> PartialEq for ValidatedStoreContext<T, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.context == other.context
+    }
+}
+
+impl<T, E> Clone for ValidatedStoreContext<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            context: self.context.clone(),
+            rejection: self.rejection.clone(),
+        }
+    }
+}
+
+impl<T, E> Debug for ValidatedStoreContext<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ValidatedStoreContext")
+            .field(&self.context)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct TestContext {
+        store: ValidatedStoreContext<i32, &'static str>,
+        notified_values: Rc<RefCell<Vec<(i32, i32)>>>,
+    }
+
+    fn setup() -> TestContext {
+        let store = StoreContext::new_validated(0, |state: &i32| match *state < 0 {
+            true => Err("negative"),
+            false => Ok(()),
+        });
+        store.set_state(1);
+        let notified_values = Rc::new(RefCell::new(vec![]));
+        store.subscribe({
+            let notified_values = notified_values.clone();
+            move |prev, next| {
+                notified_values.borrow_mut().push((*prev, *next));
+                true
+            }
+        });
+        TestContext {
+            store,
+            notified_values,
+        }
+    }
+
+    fn assert_untouched(ctx: &TestContext) {
+        assert_eq!(*ctx.store.state(), 1);
+        assert_eq!(*ctx.store.previous_state(), 0);
+        assert!(ctx.notified_values.borrow().is_empty());
+        assert_eq!(ctx.store.subscriber_count(), 1);
+    }
+
+    #[test]
+    fn try_set_state_with_invalid_state_should_leave_store_untouched() {
+        //Given
+        let ctx = setup();
+        //When
+        let result = ctx.store.try_set_state(-1);
+        //Then
+        assert_eq!(result, Err("negative"));
+        assert_untouched(&ctx);
+    }
+
+    #[test]
+    fn try_set_state_with_valid_state_should_notify() {
+        //Given
+        let ctx = setup();
+        //When
+        let result = ctx.store.try_set_state(2);
+        //Then
+        assert_eq!(result, Ok(true));
+        assert_eq!(*ctx.notified_values.borrow(), &[(1, 2)]);
+    }
+
+    #[test]
+    fn try_set_state_with_valid_state_should_validate_once() {
+        //Given
+        let validations = Rc::new(Cell::new(0));
+        let store = StoreContext::new_validated(0, {
+            let validations = validations.clone();
+            move |_: &i32| {
+                validations.set(validations.get() + 1);
+                Ok::<(), ()>(())
+            }
+        });
+        //When
+        let result = store.try_set_state(1);
+        //Then
+        assert_eq!(result, Ok(true));
+        assert_eq!(validations.get(), 1);
+    }
+
+    #[test]
+    fn try_set_state_after_rejected_set_state_should_not_return_previous_error() {
+        //Given
+        let ctx = setup();
+        ctx.store.set_state(-1);
+        //When
+        let result = ctx.store.try_set_state(2);
+        //Then
+        assert_eq!(result, Ok(true));
+    }
+
+    struct CancelAll;
+
+    impl Middleware<i32> for CancelAll {
+        fn before(&self, _prev: &i32, _next: &i32) -> Decision {
+            Decision::Cancel
+        }
+    }
+
+    #[test]
+    fn try_set_state_with_change_cancelled_by_other_middleware_should_not_be_changed() {
+        //Given
+        let ctx = setup();
+        ctx.store.add_middleware(CancelAll);
+        //When
+        let result = ctx.store.try_set_state(2);
+        //Then
+        assert_eq!(result, Ok(false));
+        assert_untouched(&ctx);
+    }
+
+    #[test]
+    fn try_update_with_invalid_state_should_leave_store_untouched() {
+        //Given
+        let ctx = setup();
+        //When
+        let result = ctx.store.try_update(|state| Ok(state - 2));
+        //Then
+        assert_eq!(result, Err("negative"));
+        assert_untouched(&ctx);
+    }
+
+    #[test]
+    fn try_update_with_error_should_leave_store_untouched() {
+        //Given
+        let ctx = setup();
+        //When
+        let result = ctx.store.try_update(|_| Err("failed"));
+        //Then
+        assert_eq!(result, Err("failed"));
+        assert_untouched(&ctx);
+    }
+
+    #[test]
+    fn set_state_with_invalid_state_should_leave_store_untouched() {
+        //Given
+        let ctx = setup();
+        //When
+        ctx.store.set_state(-1);
+        //Then
+        assert_untouched(&ctx);
+    }
+
+    #[test]
+    fn set_state_with_invalid_state_through_context_should_leave_store_untouched() {
+        //Given
+        let ctx = setup();
+        //When
+        ctx.store.context().set_state(-1);
+        //Then
+        assert_untouched(&ctx);
+    }
+}
@@ -0,0 +1,100 @@
+use super::StoreContext;
+use std::{
+    fmt::{self, Debug},
+    ops::Deref,
+};
+
+/// Store context compared by the value of its state rather than by identity.
+///
+/// `StoreContext` equality tells whether two contexts share the same store.
+/// Wrapping a context in `ValueEqStoreContext` makes two contexts equal whenever their current states are equal,
+/// which is useful for `Properties` where content equality matters.
+/// ```rust
+/// use yewv::{StoreContext, ValueEqStoreContext};
+///
+/// let first = ValueEqStoreContext::from(StoreContext::new(1));
+/// let second = ValueEqStoreContext::from(StoreContext::new(1));
+/// assert!(first == second);
+/// assert!(first.context() != second.context());
+/// ```
+pub struct ValueEqStoreContext<T, K = ()>(StoreContext<T, K>);
+
+impl<T, K> ValueEqStoreContext<T, K> {
+    /// Give the wrapped context.
+    pub fn context(&self) -> &StoreContext<T, K> {
+        &self.0
+    }
+
+    /// Unwrap the context.
+    pub fn into_inner(self) -> StoreContext<T, K> {
+        self.0
+    }
+}
+
+impl<T: PartialEq, K> PartialEq for ValueEqStoreContext<T, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 || *self.0.state() == *other.0.state()
+    }
+}
+
+impl<T, K> From<StoreContext<T, K>> for ValueEqStoreContext<T, K> {
+    fn from(context: StoreContext<T, K>) -> Self {
+        Self(context)
+    }
+}
+
+impl<T, K> Deref for ValueEqStoreContext<T, K> {
+    type Target = StoreContext<T, K>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, K> Clone for ValueEqStoreContext<T, K> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T, K> Debug for ValueEqStoreContext<T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ValueEqStoreContext").field(&self.0).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eq_with_distinct_contexts_of_equal_states_should_be_equal() {
+        //Given
+        let context = ValueEqStoreContext::from(StoreContext::new(1));
+        //When
+        let other = ValueEqStoreContext::from(StoreContext::new(1));
+        //Then
+        assert_eq!(context, other);
+    }
+
+    #[test]
+    fn eq_with_distinct_contexts_of_different_states_should_not_be_equal() {
+        //Given
+        let context = ValueEqStoreContext::from(StoreContext::new(1));
+        //When
+        let other = ValueEqStoreContext::from(StoreContext::new(2));
+        //Then
+        assert_ne!(context, other);
+    }
+
+    #[test]
+    fn eq_with_state_changed_should_compare_current_states() {
+        //Given
+        let context = ValueEqStoreContext::from(StoreContext::new(1));
+        let other = ValueEqStoreContext::from(StoreContext::new(2));
+        //When
+        other.set_state(1);
+        //Then
+        assert_eq!(context, other);
+    }
+}
@@ -0,0 +1,103 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Keys of the items inserted, removed and updated between two versions of a collection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyedDiff<K> {
+    /// Keys of the items found only in the next version, in their order.
+    pub inserted: Vec<K>,
+    /// Keys of the items found only in the previous version, in their order.
+    pub removed: Vec<K>,
+    /// Keys of the items found in both versions whose value changed, in their order in the next version.
+    pub updated: Vec<K>,
+}
+
+impl<K> KeyedDiff<K> {
+    /// Tell whether no item was inserted, removed or updated.
+    pub fn is_empty(&self) -> bool {
+        self.inserted.is_empty() && self.removed.is_empty() && self.updated.is_empty()
+    }
+}
+
+impl<K> Default for KeyedDiff<K> {
+    fn default() -> Self {
+        Self {
+            inserted: vec![],
+            removed: vec![],
+            updated: vec![],
+        }
+    }
+}
+
+/// Compare the `prev` and `next` items by `key`, telling updated items apart by `value`.
+/// Keys are expected to be unique within each version.
+pub(crate) fn keyed_diff<I, K: Eq + Hash, V: PartialEq>(
+    prev: &[I],
+    next: &[I],
+    key: impl Fn(&I) -> K,
+    value: impl Fn(&I) -> V,
+) -> KeyedDiff<K> {
+    let mut diff = KeyedDiff::default();
+    let mut remaining: HashMap<K, &I> = prev.iter().map(|item| (key(item), item)).collect();
+    for item in next {
+        let key = key(item);
+        match remaining.remove(&key) {
+            None => diff.inserted.push(key),
+            Some(prev) if value(prev) != value(item) => diff.updated.push(key),
+            Some(_) => {}
+        }
+    }
+    if !remaining.is_empty() {
+        diff.removed = prev
+            .iter()
+            .map(&key)
+            .filter(|key| remaining.contains_key(key))
+            .collect();
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diff(prev: &[(u32, &str)], next: &[(u32, &str)]) -> KeyedDiff<u32> {
+        keyed_diff(prev, next, |item| item.0, |item| item.1)
+    }
+
+    #[test]
+    fn keyed_diff_with_same_items_should_be_empty() {
+        //Given
+        let items = [(1, "a"), (2, "b")];
+        //When
+        let diff = diff(&items, &items);
+        //Then
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn keyed_diff_with_items_changed_should_give_inserted_removed_and_updated_keys() {
+        //Given
+        let prev = [(1, "a"), (2, "b"), (3, "c")];
+        //When
+        let diff = diff(&prev, &[(3, "c"), (2, "B"), (4, "d")]);
+        //Then
+        assert_eq!(
+            diff,
+            KeyedDiff {
+                inserted: vec![4],
+                removed: vec![1],
+                updated: vec![2],
+            }
+        );
+    }
+
+    #[test]
+    fn keyed_diff_with_items_reordered_should_be_empty() {
+        //Given
+        let prev = [(1, "a"), (2, "b")];
+        //When
+        let diff = diff(&prev, &[(2, "b"), (1, "a")]);
+        //Then
+        assert!(diff.is_empty());
+    }
+}
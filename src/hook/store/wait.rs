@@ -0,0 +1,116 @@
+use super::Store;
+use futures_channel::oneshot;
+use std::{cell::RefCell, future::Future, rc::Rc};
+
+impl<T: 'static> Store<T> {
+    /// Give a future resolving with the first state matching `predicate`, starting with the current state.
+    /// The future doesn't borrow the store, so it can be raced against a timeout.
+    ///
+    /// Once the future is dropped, or resolved, its subscription is dropped on the next change.
+    /// If the store is dropped before a state matches, the future never resolves.
+    /// ```rust
+    /// use futures::executor::block_on;
+    /// use yewv::Store;
+    ///
+    /// let store = Store::new(None);
+    /// let token = store.wait_for(|token| token.is_some());
+    /// store.set_state(Some("token"));
+    /// assert_eq!(*block_on(token), Some("token"));
+    /// ```
+    pub fn wait_for(
+        &self,
+        predicate: impl Fn(&T) -> bool + 'static,
+    ) -> impl Future<Output = Rc<T>> + 'static {
+        let current = self.state();
+        let receiver = (!predicate(&current)).then(|| {
+            let (sender, receiver) = oneshot::channel();
+            let sender = RefCell::new(Some(sender));
+            self.subscribe_rc(move |_, next| {
+                let mut sender = sender.borrow_mut();
+                if sender.as_ref().is_none_or(|s| s.is_canceled()) {
+                    return false;
+                }
+                if !predicate(next) {
+                    return true;
+                }
+                if let Some(sender) = sender.take() {
+                    let _ = sender.send(next.clone());
+                }
+                false
+            });
+            receiver
+        });
+        async move {
+            match receiver {
+                None => current,
+                Some(receiver) => match receiver.await {
+                    Ok(state) => state,
+                    Err(_) => std::future::pending().await,
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{executor::block_on, FutureExt};
+
+    #[test]
+    fn wait_for_with_matching_current_state_should_resolve_immediately() {
+        //Given
+        let store = Store::new(1);
+        //When
+        let state = store.wait_for(|state| *state == 1).now_or_never();
+        //Then
+        assert_eq!(state, Some(Rc::new(1)));
+        assert_eq!(store.subscriber_count(), 0);
+    }
+
+    #[test]
+    fn wait_for_with_matching_next_state_should_resolve_with_matching_state() {
+        //Given
+        let store = Store::new(0);
+        let future = store.wait_for(|state| *state > 1);
+        //When
+        for i in 1..=3 {
+            store.set_state(i);
+        }
+        //Then
+        assert_eq!(block_on(future), Rc::new(2));
+    }
+
+    #[test]
+    fn wait_for_with_no_matching_state_should_not_resolve() {
+        //Given
+        let store = Store::new(0);
+        let future = store.wait_for(|state| *state > 1);
+        //When
+        store.set_state(1);
+        //Then
+        assert_eq!(future.now_or_never(), None);
+    }
+
+    #[test]
+    fn wait_for_with_matching_state_should_drop_subscription() {
+        //Given
+        let store = Store::new(0);
+        let _future = store.wait_for(|state| *state == 1);
+        //When
+        store.set_state(1);
+        //Then
+        assert_eq!(store.subscriber_count(), 0);
+    }
+
+    #[test]
+    fn wait_for_with_future_dropped_should_drop_subscription() {
+        //Given
+        let store = Store::new(0);
+        drop(store.wait_for(|state| *state == 2));
+        //When
+        store.set_state(1);
+        //Then
+        assert_eq!(store.subscriber_count(), 0);
+    }
+}
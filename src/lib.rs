@@ -165,4 +165,6 @@
 //! - [Yew](https://github.com/yewstack/yew) - [MIT](https://github.com/yewstack/yew/blob/master/LICENSE-MIT) or [Apache-2.0](https://github.com/yewstack/yew/blob/master/LICENSE-APACHE)
 
 mod hook;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub use hook::*;
@@ -0,0 +1,233 @@
+//! Helpers to test the logic driving stores, such as services, without a browser.
+//! ```rust
+//! use yewv::{testing::TestStore, StoreContext};
+//!
+//! struct CounterService {
+//!     store: StoreContext<i32>,
+//! }
+//!
+//! impl CounterService {
+//!     fn increment(&self) {
+//!         self.store.set_state(*self.store.state() + 1);
+//!     }
+//! }
+//!
+//! let store = TestStore::new(0);
+//! let service = CounterService { store: store.context().clone() };
+//! service.increment();
+//! service.increment();
+//! store.assert_transitions(&[(0, 1), (1, 2)]);
+//! ```
+use crate::{Middleware, Store, StoreContext};
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    ops::Deref,
+    rc::{Rc, Weak},
+};
+
+type Transitions<T> = Rc<RefCell<Vec<(Rc<T>, Rc<T>)>>>;
+
+/// Store context recording every transition made to its state.
+pub struct TestStore<T> {
+    context: StoreContext<T>,
+    transitions: Transitions<T>,
+}
+
+impl<T: 'static> TestStore<T> {
+    /// Create a new test store with the given state as initial state.
+    pub fn new(initial_state: T) -> Self {
+        let context = StoreContext::new(initial_state);
+        let transitions = Transitions::default();
+        context.subscribe_rc({
+            let transitions = transitions.clone();
+            move |prev, next| {
+                transitions.borrow_mut().push((prev.clone(), next.clone()));
+                true
+            }
+        });
+        Self {
+            context,
+            transitions,
+        }
+    }
+
+    /// Give the recorded store context, to hand it to the logic under test.
+    pub fn context(&self) -> &StoreContext<T> {
+        &self.context
+    }
+
+    /// Give every `(previous, next)` state transition recorded so far, oldest first.
+    pub fn transitions(&self) -> Vec<(Rc<T>, Rc<T>)> {
+        self.transitions.borrow().clone()
+    }
+
+    /// Give the current state of the store.
+    pub fn last_state(&self) -> Rc<T> {
+        self.context.state()
+    }
+
+    /// Forget the transitions recorded so far.
+    pub fn clear_transitions(&self) {
+        self.transitions.borrow_mut().clear();
+    }
+}
+
+impl<T: PartialEq + Debug + 'static> TestStore<T> {
+    /// Assert the transitions recorded so far are the `expected` `(previous, next)` states, oldest first.
+    #[track_caller]
+    pub fn assert_transitions(&self, expected: &[(T, T)]) {
+        let transitions = self.transitions.borrow();
+        let actual: Vec<(&T, &T)> = transitions.iter().map(|(p, n)| (&**p, &**n)).collect();
+        let expected: Vec<(&T, &T)> = expected.iter().map(|(p, n)| (p, n)).collect();
+        assert_eq!(
+            actual, expected,
+            "Store transitions differ from the expected ones."
+        );
+    }
+}
+
+impl<T> Deref for TestStore<T> {
+    type Target = StoreContext<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.context
+    }
+}
+
+/// Record of every state a store went through, to replay them against another store.
+/// ```rust
+/// use yewv::{testing::Recorder, Store};
+///
+/// let store = Store::new(0);
+/// let recorder = Recorder::attach(&store);
+/// store.set_state(1);
+/// store.set_state(2);
+///
+/// let replayed = Store::new(0);
+/// recorder.replay(&replayed);
+/// assert_eq!(*replayed.state(), 2);
+/// assert_eq!(recorder.states(), vec![1, 2]);
+/// ```
+pub struct Recorder<T> {
+    states: Rc<RefCell<Vec<T>>>,
+}
+
+/// Middleware pushing each next state to its recorder, for as long as the recorder is alive.
+struct RecorderMiddleware<T> {
+    states: Weak<RefCell<Vec<T>>>,
+}
+
+impl<T: Clone> Middleware<T> for RecorderMiddleware<T> {
+    fn after(&self, _prev: &T, next: &T) {
+        if let Some(states) = self.states.upgrade() {
+            states.borrow_mut().push(next.clone());
+        }
+    }
+}
+
+impl<T: Clone + 'static> Recorder<T> {
+    /// Record every state the `store` goes through from now on, until the recorder is dropped.
+    /// Stores created with `new_deferred` are recorded once per batch of changes.
+    pub fn attach(store: &Store<T>) -> Self {
+        let states = Rc::new(RefCell::new(vec![]));
+        store.add_middleware(RecorderMiddleware {
+            states: Rc::downgrade(&states),
+        });
+        Self { states }
+    }
+
+    /// Give the states recorded so far, oldest first.
+    pub fn states(&self) -> Vec<T> {
+        self.states.borrow().clone()
+    }
+
+    /// Set the recorded states on the store `into`, in the order they were recorded.
+    pub fn replay(&self, into: &Store<T>) {
+        for state in self.states() {
+            into.set_state(state);
+        }
+    }
+
+    /// Forget the states recorded so far.
+    pub fn clear(&self) {
+        self.states.borrow_mut().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assert_transitions_with_state_changes_should_record_each_transition() {
+        //Given
+        let store = TestStore::new(0);
+        //When
+        store.set_state(1);
+        store.set_state(2);
+        //Then
+        store.assert_transitions(&[(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Store transitions differ from the expected ones.")]
+    fn assert_transitions_with_other_transitions_should_panic() {
+        //Given
+        let store = TestStore::new(0);
+        //When
+        store.set_state(1);
+        //Then
+        store.assert_transitions(&[(0, 2)]);
+    }
+
+    #[test]
+    fn last_state_with_state_changed_should_give_current_state() {
+        //Given
+        let store = TestStore::new(0);
+        //When
+        store.set_state(3);
+        //Then
+        assert_eq!(*store.last_state(), 3);
+    }
+
+    #[test]
+    fn clear_transitions_with_transitions_recorded_should_forget_them() {
+        //Given
+        let store = TestStore::new(0);
+        store.set_state(1);
+        //When
+        store.clear_transitions();
+        store.set_state(2);
+        //Then
+        store.assert_transitions(&[(1, 2)]);
+        assert_eq!(store.transitions().len(), 1);
+    }
+
+    #[test]
+    fn replay_with_states_recorded_should_set_each_state_in_order() {
+        //Given
+        let store = Store::new(0);
+        let recorder = Recorder::attach(&store);
+        store.set_state(1);
+        store.set_state(2);
+        let replayed = TestStore::new(0);
+        //When
+        recorder.replay(&replayed);
+        //Then
+        replayed.assert_transitions(&[(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn states_with_cleared_recorder_should_give_states_recorded_afterwards() {
+        //Given
+        let store = Store::new(0);
+        let recorder = Recorder::attach(&store);
+        store.set_state(1);
+        //When
+        recorder.clear();
+        store.set_state(2);
+        //Then
+        assert_eq!(recorder.states(), vec![2]);
+    }
+}
@@ -3,17 +3,18 @@ use std::time::Duration;
 
 use gloo::timers::future::sleep;
 pub use store::*;
-use yew::BaseComponent;
+use yew::{AppHandle, BaseComponent};
 
 pub async fn render_with_props<C: BaseComponent + 'static>(
     props: <C as BaseComponent>::Properties,
-) {
-    yew::Renderer::<C>::with_root_and_props(
+) -> AppHandle<C> {
+    let handle = yew::Renderer::<C>::with_root_and_props(
         gloo_utils::document().get_element_by_id("output").unwrap(),
         props,
     )
     .render();
     sleep(Duration::ZERO).await;
+    handle
 }
 
 pub async fn inner_html() -> String {
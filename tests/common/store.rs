@@ -5,6 +5,7 @@ use yew::prelude::*;
 use yew::{function_component, ContextProvider, Html};
 use yewv::*;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StoreState {
     pub value: i32,
 }
@@ -12,7 +13,16 @@ pub struct StoreState {
 #[derive(PartialEq, Clone)]
 pub enum SubscriptionType {
     Map,
+    MapWith,
+    MapDelta,
+    MapRc,
     MapRef,
+    MapWhile,
+    CloneRef,
+    ConditionalMap,
+    Memo,
+    Peek,
+    Select,
     Watch,
     WatchRef,
 }
@@ -42,7 +52,16 @@ pub fn StoreApp(props: &StoreAppProps) -> Html {
         {
             match &props.sub_type {
                 SubscriptionType::Map => html! { <StoreMapComponent render_count={props.render_count.clone()} /> },
+                SubscriptionType::MapWith => html! { <StoreMapWithComponent render_count={props.render_count.clone()} /> },
+                SubscriptionType::MapDelta => html! { <StoreMapDeltaComponent render_count={props.render_count.clone()} /> },
+                SubscriptionType::MapRc => html! { <StoreMapRcComponent render_count={props.render_count.clone()} /> },
+                SubscriptionType::Memo => html! { <StoreMemoComponent render_count={props.render_count.clone()} /> },
+                SubscriptionType::Peek => html! { <StorePeekComponent render_count={props.render_count.clone()} /> },
+                SubscriptionType::CloneRef => html! { <StoreCloneRefComponent render_count={props.render_count.clone()} /> },
+                SubscriptionType::ConditionalMap => html! { <StoreConditionalMapComponent render_count={props.render_count.clone()} /> },
+                SubscriptionType::Select => html! { <StoreSelectComponent render_count={props.render_count.clone()} /> },
                 SubscriptionType::MapRef => html! { <StoreMapRefComponent render_count={props.render_count.clone()} /> },
+                SubscriptionType::MapWhile => html! { <StoreMapWhileComponent render_count={props.render_count.clone()} /> },
                 SubscriptionType::Watch => html! { <StoreWatchComponent render_count={props.render_count.clone()} /> },
                 SubscriptionType::WatchRef => html! { <StoreWatchRefComponent render_count={props.render_count.clone()} /> },
             }
@@ -66,6 +85,89 @@ fn StoreMapComponent(props: &StoreComponentProps) -> Html {
     html! { { value } }
 }
 
+#[function_component]
+fn StoreConditionalMapComponent(props: &StoreComponentProps) -> Html {
+    let store = use_store::<StoreState>();
+
+    let is_odd = *store.map(|s| s.value % 2 == 1);
+    let label = if is_odd {
+        store.map(|s| format!("odd {}", s.value)).to_string()
+    } else {
+        String::new()
+    };
+    let value = store.map(|s| s.value);
+    *props.render_count.borrow_mut() += 1;
+    html! { format!("{}|{}", label, value) }
+}
+
+#[function_component]
+fn StoreMapWithComponent(props: &StoreComponentProps) -> Html {
+    let store = use_store::<StoreState>();
+
+    let value = store.map_with(|s| s.value, |a, b| (a - b).abs() < 2);
+    *props.render_count.borrow_mut() += 1;
+    html! { { value } }
+}
+
+#[function_component]
+fn StoreMapDeltaComponent(props: &StoreComponentProps) -> Html {
+    let store = use_store::<StoreState>();
+
+    let delta = store.map_delta(|prev, next| next.value - prev.value);
+    *props.render_count.borrow_mut() += 1;
+    html! { { delta } }
+}
+
+#[function_component]
+fn StoreMapRcComponent(props: &StoreComponentProps) -> Html {
+    let store = use_store::<StoreState>();
+    let zero = (*use_state(|| Rc::new(0))).clone();
+
+    let value = store.map_rc(move |s| match s.value {
+        0 => zero.clone(),
+        value => Rc::new(value),
+    });
+    *props.render_count.borrow_mut() += 1;
+    html! { { value } }
+}
+
+#[function_component]
+fn StoreMemoComponent(props: &StoreComponentProps) -> Html {
+    let store = use_store::<StoreState>();
+
+    let value = store.memo(|s| s.value / 2, |s| s.value);
+    *props.render_count.borrow_mut() += 1;
+    html! { { value } }
+}
+
+#[function_component]
+fn StorePeekComponent(props: &StoreComponentProps) -> Html {
+    let store = use_store::<StoreState>();
+
+    let value = store.peek(|s| s.value);
+    *props.render_count.borrow_mut() += 1;
+    html! { { value } }
+}
+
+#[function_component]
+fn StoreCloneRefComponent(props: &StoreComponentProps) -> Html {
+    let store = use_store::<StoreState>();
+
+    let value = store.clone_ref(|s| &s.value);
+    *props.render_count.borrow_mut() += 1;
+    html! { { value } }
+}
+
+#[function_component]
+fn StoreSelectComponent(props: &StoreComponentProps) -> Html {
+    let store = use_store_selector::<StoreState>();
+
+    let (value, is_even) =
+        store.select((|s: &StoreState| s.value, |s: &StoreState| s.value % 2 == 0));
+    *props.render_count.borrow_mut() += 1;
+    html! { format!("{} {}", value, is_even) }
+}
+
 #[function_component]
 fn StoreMapRefComponent(props: &StoreComponentProps) -> Html {
     let store = use_store::<StoreState>();
@@ -75,6 +177,18 @@ fn StoreMapRefComponent(props: &StoreComponentProps) -> Html {
     html! { { value } }
 }
 
+#[function_component]
+fn StoreMapWhileComponent(props: &StoreComponentProps) -> Html {
+    let store = use_store::<StoreState>();
+
+    let value = store.map_while(|s| s.value < 10, |s| s.value / 2);
+    *props.render_count.borrow_mut() += 1;
+    match value {
+        Some(value) => html! { { value } },
+        None => html! { "inactive" },
+    }
+}
+
 #[function_component]
 fn StoreWatchComponent(props: &StoreComponentProps) -> Html {
     let store = use_store::<StoreState>();
@@ -0,0 +1,67 @@
+#![cfg(feature = "timers")]
+
+mod common;
+
+use common::*;
+use gloo::timers::future::sleep;
+use std::{cell::RefCell, rc::Rc, time::Duration};
+use wasm_bindgen_test::wasm_bindgen_test;
+use yewv::{NotifyPolicy, StoreContext};
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn setup() -> StoreAppProps {
+    StoreAppProps {
+        sub_type: SubscriptionType::Map,
+        context: StoreContext::new_with_policy(
+            StoreState { value: 0 },
+            NotifyPolicy::AnimationFrame,
+        ),
+        render_count: Rc::new(RefCell::new(0)),
+    }
+}
+
+async fn next_frame() {
+    sleep(Duration::from_millis(50)).await;
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_three_times_within_frame_should_rerender_once() {
+    //Given
+    let props = setup();
+    render_with_props::<StoreApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    props.context.set_state(StoreState { value: 2 });
+    props.context.set_state(StoreState { value: 3 });
+    next_frame().await;
+    //Then
+    assert_eq!(&inner_html().await, "3");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_should_update_state_immediately() {
+    //Given
+    let props = setup();
+    render_with_props::<StoreApp>(props.clone()).await;
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    assert_eq!(props.context.state().value, 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_flush_should_notify_before_next_frame() {
+    //Given
+    let props = setup();
+    render_with_props::<StoreApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    props.context.set_state(StoreState { value: 1 });
+    //When
+    props.context.flush();
+    //Then
+    assert_eq!(&inner_html().await, "1");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
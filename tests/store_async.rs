@@ -0,0 +1,36 @@
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yewv::spawn_action;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn on_set_state_async_with_resolved_future_should_render_new_value() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::Map);
+    render_with_props::<StoreApp>(props.clone()).await;
+    //When
+    props
+        .context
+        .set_state_async(async { StoreState { value: 1 } });
+    //Then
+    wait().await;
+    assert_eq!(&inner_html().await, "1");
+}
+
+#[wasm_bindgen_test]
+async fn on_spawn_action_with_store_update_should_render_new_value() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::Map);
+    render_with_props::<StoreApp>(props.clone()).await;
+    let context = props.context.clone();
+    //When
+    spawn_action(async move { context.set_state(StoreState { value: 2 }) });
+    //Then
+    wait().await;
+    assert_eq!(&inner_html().await, "2");
+}
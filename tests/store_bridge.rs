@@ -0,0 +1,140 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Clone)]
+struct BridgeAppProps {
+    with_selector: bool,
+    context: StoreContext<StoreState>,
+    message_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn BridgeApp(props: &BridgeAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<StoreState>> context={props.context.clone()}>
+            <div id={"result"}>
+                <BridgeComponent
+                    with_selector={props.with_selector}
+                    message_count={props.message_count.clone()}
+                />
+            </div>
+        </ContextProvider<StoreContext<StoreState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct BridgeComponentProps {
+    with_selector: bool,
+    message_count: Rc<RefCell<i32>>,
+}
+
+enum Msg {
+    ValueChanged(i32),
+}
+
+struct BridgeComponent {
+    value: i32,
+    bridge: StoreBridge<StoreState, Self>,
+}
+
+impl Component for BridgeComponent {
+    type Message = Msg;
+    type Properties = BridgeComponentProps;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (context, _) = ctx
+            .link()
+            .context::<StoreContext<StoreState>>(Callback::noop())
+            .unwrap();
+        let bridge = if ctx.props().with_selector {
+            StoreBridge::with_selector(
+                ctx.link(),
+                context,
+                |s| s.value / 2,
+                |value| Msg::ValueChanged(*value),
+            )
+        } else {
+            StoreBridge::new(ctx.link(), context, |s| Msg::ValueChanged(s.value))
+        };
+        Self { value: 0, bridge }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        *ctx.props().message_count.borrow_mut() += 1;
+        match msg {
+            Msg::ValueChanged(value) => self.value = value,
+        }
+        true
+    }
+
+    fn view(&self, _ctx: &Context<Self>) -> Html {
+        html! { format!("{} {}", self.value, self.bridge.state().value) }
+    }
+}
+
+fn setup(with_selector: bool) -> BridgeAppProps {
+    BridgeAppProps {
+        with_selector,
+        context: StoreContext::new(StoreState { value: 0 }),
+        message_count: Rc::new(RefCell::new(0)),
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_should_send_message() {
+    //Given
+    let props = setup(false);
+    render_with_props::<BridgeApp>(props.clone()).await;
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    assert_eq!(&inner_html().await, "1 1");
+    assert_eq!(*props.message_count.borrow(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_selected_value_unchanged_should_not_send_message() {
+    //Given
+    let props = setup(true);
+    render_with_props::<BridgeApp>(props.clone()).await;
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    wait().await;
+    assert_eq!(*props.message_count.borrow(), 0);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_selected_value_changed_should_send_message() {
+    //Given
+    let props = setup(true);
+    render_with_props::<BridgeApp>(props.clone()).await;
+    //When
+    props.context.set_state(StoreState { value: 2 });
+    //Then
+    assert_eq!(&inner_html().await, "1 2");
+    assert_eq!(*props.message_count.borrow(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_after_unmount_should_unsubscribe() {
+    //Given
+    let props = setup(false);
+    let app = render_with_props::<BridgeApp>(props.clone()).await;
+    app.destroy();
+    wait().await;
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    assert_eq!(*props.message_count.borrow(), 0);
+    assert_eq!(props.context.subscriber_count(), 0);
+}
@@ -0,0 +1,225 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+const EPSILON: f64 = 0.1;
+
+struct FloatState {
+    value: f64,
+}
+
+fn approx_eq(prev: &f64, next: &f64) -> bool {
+    (prev - next).abs() < EPSILON
+}
+
+#[derive(PartialEq, Clone)]
+enum CompareType {
+    MapWith,
+    MapRefWith,
+    WatchWith,
+    WatchRefWith,
+    MapWithIncrease,
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct CompareAppProps {
+    compare_type: CompareType,
+    context: StoreContext<FloatState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn CompareApp(props: &CompareAppProps) -> Html {
+    let render_count = props.render_count.clone();
+    html! {
+        <ContextProvider<StoreContext<FloatState>> context={props.context.clone()}>
+        <div id={"result"}>
+        {
+            match &props.compare_type {
+                CompareType::MapWith => html! { <MapWithComponent {render_count} /> },
+                CompareType::MapRefWith => html! { <MapRefWithComponent {render_count} /> },
+                CompareType::WatchWith => html! { <WatchWithComponent {render_count} /> },
+                CompareType::WatchRefWith => html! { <WatchRefWithComponent {render_count} /> },
+                CompareType::MapWithIncrease => html! { <MapWithIncreaseComponent {render_count} /> },
+            }
+        }
+        </div>
+        </ContextProvider<StoreContext<FloatState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct CompareComponentProps {
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn MapWithComponent(props: &CompareComponentProps) -> Html {
+    let store = use_store::<FloatState>();
+
+    let value = store.map_with(|s| s.value, approx_eq);
+    *props.render_count.borrow_mut() += 1;
+    html! { { value } }
+}
+
+#[function_component]
+fn MapWithIncreaseComponent(props: &CompareComponentProps) -> Html {
+    let store = use_store::<FloatState>();
+
+    let value = store.map_with(|s| s.value, |prev, next| next <= prev);
+    *props.render_count.borrow_mut() += 1;
+    html! { { value } }
+}
+
+#[function_component]
+fn MapRefWithComponent(props: &CompareComponentProps) -> Html {
+    let store = use_store::<FloatState>();
+
+    let value = *store.map_ref_with(|s| &s.value, approx_eq);
+    *props.render_count.borrow_mut() += 1;
+    html! { { value } }
+}
+
+#[function_component]
+fn WatchWithComponent(props: &CompareComponentProps) -> Html {
+    let store = use_store::<FloatState>();
+
+    store.watch_with(|s| s.value, approx_eq);
+    *props.render_count.borrow_mut() += 1;
+    html! { { store.state().value } }
+}
+
+#[function_component]
+fn WatchRefWithComponent(props: &CompareComponentProps) -> Html {
+    let store = use_store::<FloatState>();
+
+    store.watch_ref_with(|s| &s.value, approx_eq);
+    *props.render_count.borrow_mut() += 1;
+    html! { { store.state().value } }
+}
+
+fn setup(compare_type: CompareType) -> CompareAppProps {
+    CompareAppProps {
+        compare_type,
+        context: StoreContext::new(FloatState { value: 1.0 }),
+        render_count: Rc::new(RefCell::new(0)),
+    }
+}
+
+async fn render_count_after_change(compare_type: CompareType, value: f64) -> i32 {
+    let props = setup(compare_type);
+    render_with_props::<CompareApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    props.context.set_state(FloatState { value });
+    wait().await;
+    let count = *props.render_count.borrow();
+    count - render_count
+}
+
+#[wasm_bindgen_test]
+async fn map_with_on_store_value_changed_within_epsilon_should_not_rerender() {
+    //Given
+    let compare_type = CompareType::MapWith;
+    //When
+    let renders = render_count_after_change(compare_type, 1.05).await;
+    //Then
+    assert_eq!(renders, 0);
+}
+
+#[wasm_bindgen_test]
+async fn map_with_on_store_value_changed_beyond_epsilon_should_rerender() {
+    //Given
+    let compare_type = CompareType::MapWith;
+    //When
+    let renders = render_count_after_change(compare_type, 1.5).await;
+    //Then
+    assert_eq!(renders, 1);
+}
+
+#[wasm_bindgen_test]
+async fn map_ref_with_on_store_value_changed_within_epsilon_should_not_rerender() {
+    //Given
+    let compare_type = CompareType::MapRefWith;
+    //When
+    let renders = render_count_after_change(compare_type, 1.05).await;
+    //Then
+    assert_eq!(renders, 0);
+}
+
+#[wasm_bindgen_test]
+async fn map_ref_with_on_store_value_changed_beyond_epsilon_should_rerender() {
+    //Given
+    let compare_type = CompareType::MapRefWith;
+    //When
+    let renders = render_count_after_change(compare_type, 1.5).await;
+    //Then
+    assert_eq!(renders, 1);
+}
+
+#[wasm_bindgen_test]
+async fn watch_with_on_store_value_changed_within_epsilon_should_not_rerender() {
+    //Given
+    let compare_type = CompareType::WatchWith;
+    //When
+    let renders = render_count_after_change(compare_type, 1.05).await;
+    //Then
+    assert_eq!(renders, 0);
+}
+
+#[wasm_bindgen_test]
+async fn watch_with_on_store_value_changed_beyond_epsilon_should_rerender() {
+    //Given
+    let compare_type = CompareType::WatchWith;
+    //When
+    let renders = render_count_after_change(compare_type, 1.5).await;
+    //Then
+    assert_eq!(renders, 1);
+}
+
+#[wasm_bindgen_test]
+async fn watch_ref_with_on_store_value_changed_within_epsilon_should_not_rerender() {
+    //Given
+    let compare_type = CompareType::WatchRefWith;
+    //When
+    let renders = render_count_after_change(compare_type, 1.05).await;
+    //Then
+    assert_eq!(renders, 0);
+}
+
+#[wasm_bindgen_test]
+async fn watch_ref_with_on_store_value_changed_beyond_epsilon_should_rerender() {
+    //Given
+    let compare_type = CompareType::WatchRefWith;
+    //When
+    let renders = render_count_after_change(compare_type, 1.5).await;
+    //Then
+    assert_eq!(renders, 1);
+}
+
+#[wasm_bindgen_test]
+async fn map_with_on_store_value_decreased_should_give_previous_then_next_to_comparator() {
+    //Given
+    let compare_type = CompareType::MapWithIncrease;
+    //When
+    let renders = render_count_after_change(compare_type, 0.5).await;
+    //Then
+    assert_eq!(renders, 0);
+}
+
+#[wasm_bindgen_test]
+async fn map_with_on_store_value_increased_should_give_previous_then_next_to_comparator() {
+    //Given
+    let compare_type = CompareType::MapWithIncrease;
+    //When
+    let renders = render_count_after_change(compare_type, 1.5).await;
+    //Then
+    assert_eq!(renders, 1);
+}
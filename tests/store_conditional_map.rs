@@ -0,0 +1,55 @@
+mod common;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn on_init_with_condition_unmet_should_map_remaining_values() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::ConditionalMap);
+    //When
+    render_with_props::<StoreApp>(props).await;
+    //Then
+    assert_eq!(&inner_html().await, "|0");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_condition_met_should_map_conditional_value() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::ConditionalMap);
+    render_with_props::<StoreApp>(props.clone()).await;
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    assert_eq!(&inner_html().await, "odd 1|1");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_fewer_maps_called_should_map_new_value() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::ConditionalMap);
+    render_with_props::<StoreApp>(props.clone()).await;
+    props.context.set_state(StoreState { value: 1 });
+    wait().await;
+    //When
+    props.context.set_state(StoreState { value: 2 });
+    //Then
+    assert_eq!(&inner_html().await, "|2");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_maps_called_again_should_map_new_values() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::ConditionalMap);
+    render_with_props::<StoreApp>(props.clone()).await;
+    for value in 1..3 {
+        props.context.set_state(StoreState { value });
+        wait().await;
+    }
+    //When
+    props.context.set_state(StoreState { value: 3 });
+    //Then
+    assert_eq!(&inner_html().await, "odd 3|3");
+}
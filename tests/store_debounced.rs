@@ -0,0 +1,128 @@
+#![cfg(feature = "timers")]
+
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use common::*;
+use gloo::timers::future::sleep;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+const DEBOUNCE: Duration = Duration::from_millis(20);
+
+#[derive(Properties, PartialEq, Clone)]
+struct DebouncedAppProps {
+    with_watch: bool,
+    context: StoreContext<StoreState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn DebouncedApp(props: &DebouncedAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<StoreState>> context={props.context.clone()}>
+            <div id={"result"}>
+                <DebouncedComponent
+                    with_watch={props.with_watch}
+                    render_count={props.render_count.clone()}
+                />
+            </div>
+        </ContextProvider<StoreContext<StoreState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct DebouncedComponentProps {
+    with_watch: bool,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn DebouncedComponent(props: &DebouncedComponentProps) -> Html {
+    let store = use_store::<StoreState>();
+
+    let value = if props.with_watch {
+        store.watch_debounced(DEBOUNCE, |s| s.value);
+        store.state().value
+    } else {
+        *store.map_debounced(DEBOUNCE, |s| s.value)
+    };
+    *props.render_count.borrow_mut() += 1;
+    html! { { value } }
+}
+
+fn setup(with_watch: bool) -> DebouncedAppProps {
+    DebouncedAppProps {
+        with_watch,
+        context: StoreContext::new(StoreState { value: 0 }),
+        render_count: Rc::new(RefCell::new(0)),
+    }
+}
+
+async fn write_three_times(context: &StoreContext<StoreState>) {
+    for value in 1..=3 {
+        context.set_state(StoreState { value });
+        wait().await;
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_three_times_should_render_latest_value_once() {
+    //Given
+    let props = setup(false);
+    render_with_props::<DebouncedApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    write_three_times(&props.context).await;
+    sleep(DEBOUNCE * 2).await;
+    //Then
+    assert_eq!(&inner_html().await, "3");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_watch_should_render_latest_value_once() {
+    //Given
+    let props = setup(true);
+    render_with_props::<DebouncedApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    write_three_times(&props.context).await;
+    sleep(DEBOUNCE * 2).await;
+    //Then
+    assert_eq!(&inner_html().await, "3");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_before_duration_should_not_render() {
+    //Given
+    let props = setup(false);
+    render_with_props::<DebouncedApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    write_three_times(&props.context).await;
+    //Then
+    assert_eq!(&inner_html().await, "0");
+    assert_eq!(*props.render_count.borrow(), render_count);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_then_unmount_should_cancel_timer() {
+    //Given
+    let props = setup(false);
+    let app = render_with_props::<DebouncedApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    app.destroy();
+    sleep(DEBOUNCE * 2).await;
+    //Then
+    assert_eq!(*props.render_count.borrow(), render_count);
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::*;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_test::wasm_bindgen_test;
+use yewv::StoreContext;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn setup() -> StoreAppProps {
+    StoreAppProps {
+        sub_type: SubscriptionType::Map,
+        context: StoreContext::new_deferred(StoreState { value: 0 }),
+        render_count: Rc::new(RefCell::new(0)),
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_twice_with_deferred_store_should_rerender_once() {
+    //Given
+    let props = setup();
+    render_with_props::<StoreApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    props.context.set_state(StoreState { value: 2 });
+    //Then
+    assert_eq!(&inner_html().await, "2");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_deferred_store_should_update_state_immediately() {
+    //Given
+    let props = setup();
+    render_with_props::<StoreApp>(props.clone()).await;
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    assert_eq!(props.context.state().value, 1);
+}
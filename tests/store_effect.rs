@@ -0,0 +1,106 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Clone)]
+struct EffectAppProps {
+    context: StoreContext<StoreState>,
+    log: Rc<RefCell<Vec<String>>>,
+}
+
+#[function_component]
+fn EffectApp(props: &EffectAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<StoreState>> context={props.context.clone()}>
+            <div id={"result"}>
+                <EffectComponent log={props.log.clone()} />
+            </div>
+        </ContextProvider<StoreContext<StoreState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct EffectComponentProps {
+    log: Rc<RefCell<Vec<String>>>,
+}
+
+#[function_component]
+fn EffectComponent(props: &EffectComponentProps) -> Html {
+    let log = props.log.clone();
+    use_store_effect(
+        |s: &StoreState| s.value / 2,
+        move |value| {
+            log.borrow_mut().push(format!("run {}", value));
+            let value = *value;
+            move || log.borrow_mut().push(format!("cleanup {}", value))
+        },
+    );
+    html! {}
+}
+
+struct TestContext {
+    props: EffectAppProps,
+}
+
+fn setup() -> TestContext {
+    TestContext {
+        props: EffectAppProps {
+            context: StoreContext::new(StoreState { value: 0 }),
+            log: Rc::new(RefCell::new(vec![])),
+        },
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_init_should_run_effect() {
+    //Given
+    let ctx = setup();
+    //When
+    render_with_props::<EffectApp>(ctx.props.clone()).await;
+    //Then
+    assert_eq!(*ctx.props.log.borrow(), vec!["run 0"]);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_selected_value_changed_should_clean_up_and_run_effect() {
+    //Given
+    let ctx = setup();
+    render_with_props::<EffectApp>(ctx.props.clone()).await;
+    //When
+    ctx.props.context.set_state(StoreState { value: 2 });
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.log.borrow(), vec!["run 0", "cleanup 0", "run 1"]);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_selected_value_unchanged_should_not_run_effect() {
+    //Given
+    let ctx = setup();
+    render_with_props::<EffectApp>(ctx.props.clone()).await;
+    //When
+    ctx.props.context.set_state(StoreState { value: 1 });
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.log.borrow(), vec!["run 0"]);
+}
+
+#[wasm_bindgen_test]
+async fn on_unmount_should_clean_up_effect() {
+    //Given
+    let ctx = setup();
+    let app = render_with_props::<EffectApp>(ctx.props.clone()).await;
+    //When
+    app.destroy();
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.log.borrow(), vec!["run 0", "cleanup 0"]);
+}
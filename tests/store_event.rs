@@ -0,0 +1,99 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Clone)]
+struct EventAppProps {
+    context: EventBusContext<i32>,
+    handled_events: Rc<RefCell<Vec<i32>>>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn EventApp(props: &EventAppProps) -> Html {
+    html! {
+        <ContextProvider<EventBusContext<i32>> context={props.context.clone()}>
+            <div id={"result"}>
+                <EventComponent
+                    handled_events={props.handled_events.clone()}
+                    render_count={props.render_count.clone()}
+                />
+            </div>
+        </ContextProvider<EventBusContext<i32>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct EventComponentProps {
+    handled_events: Rc<RefCell<Vec<i32>>>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn EventComponent(props: &EventComponentProps) -> Html {
+    use_store_event::<i32, _>({
+        let handled_events = props.handled_events.clone();
+        move |event| handled_events.borrow_mut().push(*event)
+    });
+    *props.render_count.borrow_mut() += 1;
+    html! {}
+}
+
+struct TestContext {
+    props: EventAppProps,
+}
+
+fn setup() -> TestContext {
+    TestContext {
+        props: EventAppProps {
+            context: EventBusContext::new(),
+            handled_events: Rc::new(RefCell::new(vec![])),
+            render_count: Rc::new(RefCell::new(0)),
+        },
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_event_emitted_with_handler_mounted_should_handle_event() {
+    //Given
+    let ctx = setup();
+    render_with_props::<EventApp>(ctx.props.clone()).await;
+    //When
+    ctx.props.context.emit(1);
+    //Then
+    assert_eq!(*ctx.props.handled_events.borrow(), &[1]);
+}
+
+#[wasm_bindgen_test]
+async fn on_event_emitted_with_handler_mounted_should_not_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<EventApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.emit(1);
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count);
+}
+
+#[wasm_bindgen_test]
+async fn on_event_emitted_with_handler_unmounted_should_drop_event() {
+    //Given
+    let ctx = setup();
+    let app = render_with_props::<EventApp>(ctx.props.clone()).await;
+    app.destroy();
+    wait().await;
+    //When
+    ctx.props.context.emit(1);
+    //Then
+    assert!(ctx.props.handled_events.borrow().is_empty());
+}
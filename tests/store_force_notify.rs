@@ -0,0 +1,56 @@
+mod common;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+async fn assert_force_notify_rerenders(sub_type: SubscriptionType) {
+    //Given
+    let props = StoreAppProps::new(sub_type);
+    render_with_props::<StoreApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.force_notify();
+    //Then
+    wait().await;
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_force_notify_with_map_should_rerender() {
+    assert_force_notify_rerenders(SubscriptionType::Map).await;
+}
+
+#[wasm_bindgen_test]
+async fn on_force_notify_with_map_ref_should_rerender() {
+    assert_force_notify_rerenders(SubscriptionType::MapRef).await;
+}
+
+#[wasm_bindgen_test]
+async fn on_force_notify_with_watch_should_rerender() {
+    assert_force_notify_rerenders(SubscriptionType::Watch).await;
+}
+
+#[wasm_bindgen_test]
+async fn on_force_notify_with_watch_ref_should_rerender() {
+    assert_force_notify_rerenders(SubscriptionType::WatchRef).await;
+}
+
+#[wasm_bindgen_test]
+async fn on_force_notify_with_memo_should_rerender() {
+    assert_force_notify_rerenders(SubscriptionType::Memo).await;
+}
+
+#[wasm_bindgen_test]
+async fn on_force_notify_with_peek_should_not_rerender() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::Peek);
+    render_with_props::<StoreApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.force_notify();
+    //Then
+    wait().await;
+    assert_eq!(*props.render_count.borrow(), render_count);
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Clone)]
+struct InspectAppProps {
+    context: StoreContext<StoreState>,
+    inspected_values: Rc<RefCell<Vec<i32>>>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn InspectApp(props: &InspectAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<StoreState>> context={props.context.clone()}>
+            <div id={"result"}>
+                <InspectComponent
+                    inspected_values={props.inspected_values.clone()}
+                    render_count={props.render_count.clone()}
+                />
+            </div>
+        </ContextProvider<StoreContext<StoreState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct InspectComponentProps {
+    inspected_values: Rc<RefCell<Vec<i32>>>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn InspectComponent(props: &InspectComponentProps) -> Html {
+    let store = use_store::<StoreState>();
+
+    store.inspect(|s| s.value / 2, {
+        let inspected_values = props.inspected_values.clone();
+        move |value| inspected_values.borrow_mut().push(*value)
+    });
+    *props.render_count.borrow_mut() += 1;
+    html! {}
+}
+
+struct TestContext {
+    props: InspectAppProps,
+}
+
+fn setup() -> TestContext {
+    TestContext {
+        props: InspectAppProps {
+            context: StoreContext::new(StoreState { value: 0 }),
+            inspected_values: Rc::new(RefCell::new(vec![])),
+            render_count: Rc::new(RefCell::new(0)),
+        },
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_inspected_value_changed_should_invoke_callback() {
+    //Given
+    let ctx = setup();
+    render_with_props::<InspectApp>(ctx.props.clone()).await;
+    //When
+    ctx.props.context.set_state(StoreState { value: 2 });
+    //Then
+    assert_eq!(*ctx.props.inspected_values.borrow(), &[1]);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_inspected_value_unchanged_should_not_invoke_callback() {
+    //Given
+    let ctx = setup();
+    render_with_props::<InspectApp>(ctx.props.clone()).await;
+    //When
+    ctx.props.context.set_state(StoreState { value: 1 });
+    //Then
+    assert!(ctx.props.inspected_values.borrow().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_inspected_value_changed_should_not_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<InspectApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.set_state(StoreState { value: 2 });
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count);
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn load_json_with_valid_json_should_rerender() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::Map);
+    render_with_props::<StoreApp>(props.clone()).await;
+    //When
+    props.context.load_json(r#"{"value":1}"#).unwrap();
+    //Then
+    assert_eq!(&inner_html().await, "1");
+}
+
+#[wasm_bindgen_test]
+async fn load_json_with_invalid_json_should_not_rerender() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::Map);
+    render_with_props::<StoreApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    let result = props.context.load_json("{");
+    //Then
+    wait().await;
+    assert!(result.is_err());
+    assert_eq!(*props.render_count.borrow(), render_count);
+}
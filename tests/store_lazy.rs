@@ -0,0 +1,70 @@
+mod common;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Clone)]
+struct LazyAppProps {
+    context: StoreContext<StoreState>,
+    consumers: usize,
+}
+
+#[function_component]
+fn LazyApp(props: &LazyAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<StoreState>> context={props.context.clone()}>
+            <div id={"result"}>
+                { for (0..props.consumers).map(|_| html! { <LazyComponent /> }) }
+            </div>
+        </ContextProvider<StoreContext<StoreState>>>
+    }
+}
+
+#[function_component]
+fn LazyComponent() -> Html {
+    let store = use_store::<StoreState>();
+
+    let value = store.map(|s| s.value);
+    html! { { value } }
+}
+
+fn setup(consumers: usize) -> (LazyAppProps, Rc<Cell<usize>>) {
+    let init_count = Rc::new(Cell::new(0));
+    let context = StoreContext::new_lazy({
+        let init_count = init_count.clone();
+        move || {
+            init_count.set(init_count.get() + 1);
+            StoreState { value: 1 }
+        }
+    });
+    (LazyAppProps { context, consumers }, init_count)
+}
+
+#[wasm_bindgen_test]
+async fn on_init_without_consumer_should_not_create_store() {
+    //Given
+    let (props, init_count) = setup(0);
+    //When
+    render_with_props::<LazyApp>(props).await;
+    //Then
+    assert_eq!(&inner_html().await, "");
+    assert_eq!(init_count.get(), 0);
+}
+
+#[wasm_bindgen_test]
+async fn on_init_with_consumers_should_create_store_once() {
+    //Given
+    let (props, init_count) = setup(2);
+    //When
+    render_with_props::<LazyApp>(props).await;
+    //Then
+    assert_eq!(&inner_html().await, "11");
+    assert_eq!(init_count.get(), 1);
+}
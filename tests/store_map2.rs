@@ -0,0 +1,129 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+struct LimitState {
+    limit: i32,
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct Map2AppProps {
+    context: StoreContext<StoreState>,
+    limit_context: StoreContext<LimitState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn Map2App(props: &Map2AppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<StoreState>> context={props.context.clone()}>
+            <ContextProvider<StoreContext<LimitState>> context={props.limit_context.clone()}>
+                <div id={"result"}>
+                    <Map2Component render_count={props.render_count.clone()} />
+                </div>
+            </ContextProvider<StoreContext<LimitState>>>
+        </ContextProvider<StoreContext<StoreState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct Map2ComponentProps {
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn Map2Component(props: &Map2ComponentProps) -> Html {
+    let (store, limit_store) = use_stores::<(StoreState, LimitState)>();
+
+    let exceeded = map2(&store, &limit_store, |s, l| s.value > l.limit);
+    *props.render_count.borrow_mut() += 1;
+    html! { { exceeded } }
+}
+
+fn setup() -> Map2AppProps {
+    Map2AppProps {
+        context: StoreContext::new(StoreState { value: 0 }),
+        limit_context: StoreContext::new(LimitState { limit: 5 }),
+        render_count: Rc::new(RefCell::new(0)),
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_first_store_changed_with_combined_value_changed_should_render_once() {
+    //Given
+    let props = setup();
+    render_with_props::<Map2App>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 6 });
+    //Then
+    assert_eq!(&inner_html().await, "true");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_second_store_changed_with_combined_value_changed_should_render_once() {
+    //Given
+    let props = setup();
+    render_with_props::<Map2App>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.limit_context.set_state(LimitState { limit: -1 });
+    //Then
+    assert_eq!(&inner_html().await, "true");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_stores_changed_with_combined_value_unchanged_should_not_rerender() {
+    //Given
+    let props = setup();
+    render_with_props::<Map2App>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    props.limit_context.set_state(LimitState { limit: 4 });
+    //Then
+    wait().await;
+    assert_eq!(&inner_html().await, "false");
+    assert_eq!(*props.render_count.borrow(), render_count);
+}
+
+#[wasm_bindgen_test]
+async fn on_both_stores_changed_in_same_tick_should_render_final_value_once() {
+    //Given
+    let props = setup();
+    render_with_props::<Map2App>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 6 });
+    props.limit_context.set_state(LimitState { limit: 10 });
+    props.context.set_state(StoreState { value: 11 });
+    //Then
+    assert_eq!(&inner_html().await, "true");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_component_destroyed_with_both_contexts_dropped_should_release_states() {
+    //Given
+    let props = setup();
+    let app = render_with_props::<Map2App>(props.clone()).await;
+    let state = Rc::downgrade(&props.context.state());
+    let limit_state = Rc::downgrade(&props.limit_context.state());
+    //When
+    app.destroy();
+    wait().await;
+    drop(props);
+    //Then
+    assert!(state.upgrade().is_none());
+    assert!(limit_state.upgrade().is_none());
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Clone)]
+struct ListState {
+    values: Vec<i32>,
+    shown: Vec<usize>,
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct ListAppProps {
+    context: StoreContext<ListState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn ListApp(props: &ListAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<ListState>> context={props.context.clone()}>
+            <div id={"result"}>
+                <ListComponent render_count={props.render_count.clone()} />
+            </div>
+        </ContextProvider<StoreContext<ListState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ListComponentProps {
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn ListComponent(props: &ListComponentProps) -> Html {
+    let store = use_store::<ListState>();
+
+    let shown = store.map(|s| s.shown.clone());
+    let values: Vec<String> = shown
+        .iter()
+        .map(|&index| store.map_by_key(index, move |s| s.values.get(index).copied()))
+        .map(|value| value.map(|value| value.to_string()).unwrap_or_default())
+        .collect();
+    *props.render_count.borrow_mut() += 1;
+    html! { values.join(",") }
+}
+
+struct TestContext {
+    props: ListAppProps,
+}
+
+fn setup() -> TestContext {
+    TestContext {
+        props: ListAppProps {
+            context: StoreContext::new(ListState {
+                values: vec![0, 1, 2],
+                shown: vec![0, 1],
+            }),
+            render_count: Rc::new(RefCell::new(0)),
+        },
+    }
+}
+
+fn update(ctx: &TestContext, f: impl FnOnce(&mut ListState)) {
+    let mut state = (*ctx.props.context.state()).clone();
+    f(&mut state);
+    ctx.props.context.set_state(state);
+}
+
+#[wasm_bindgen_test]
+async fn on_init_with_keys_in_loop_should_map_each_value() {
+    //Given
+    let ctx = setup();
+    //When
+    render_with_props::<ListApp>(ctx.props.clone()).await;
+    //Then
+    assert_eq!(&inner_html().await, "0,1");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_shown_key_should_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<ListApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    update(&ctx, |s| s.values[1] = 10);
+    //Then
+    assert_eq!(&inner_html().await, "0,10");
+    assert_eq!(*ctx.props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_hidden_key_should_not_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<ListApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    update(&ctx, |s| s.values[2] = 20);
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_key_removed_from_loop_should_not_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<ListApp>(ctx.props.clone()).await;
+    update(&ctx, |s| s.shown = vec![1]);
+    wait().await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    update(&ctx, |s| s.values[0] = 10);
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_keys_reordered_should_map_each_value() {
+    //Given
+    let ctx = setup();
+    render_with_props::<ListApp>(ctx.props.clone()).await;
+    //When
+    update(&ctx, |s| s.shown = vec![2, 0, 1]);
+    //Then
+    assert_eq!(&inner_html().await, "2,0,1");
+}
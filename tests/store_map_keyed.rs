@@ -0,0 +1,122 @@
+mod common;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+struct ItemsState {
+    items: HashMap<u32, i32>,
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct ItemsAppProps {
+    context: StoreContext<ItemsState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn ItemsApp(props: &ItemsAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<ItemsState>> context={props.context.clone()}>
+            <div id={"result"}>
+                <ItemComponent render_count={props.render_count.clone()} />
+            </div>
+        </ContextProvider<StoreContext<ItemsState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ItemComponentProps {
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn ItemComponent(props: &ItemComponentProps) -> Html {
+    let store = use_store::<ItemsState>();
+
+    let item = store.map_keyed(1, |s, key| s.items.get(key));
+    *props.render_count.borrow_mut() += 1;
+    match item {
+        Some(item) => html! { { *item } },
+        None => html! { "none" },
+    }
+}
+
+struct TestContext {
+    props: ItemsAppProps,
+}
+
+fn setup() -> TestContext {
+    TestContext {
+        props: ItemsAppProps {
+            context: StoreContext::new(ItemsState {
+                items: HashMap::from([(1, 0), (2, 0)]),
+            }),
+            render_count: Rc::new(RefCell::new(0)),
+        },
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_init_with_existing_key_should_map_entry() {
+    //Given
+    let ctx = setup();
+    //When
+    render_with_props::<ItemsApp>(ctx.props).await;
+    //Then
+    assert_eq!(&inner_html().await, "0");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_observed_entry_changed_should_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<ItemsApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.set_state(ItemsState {
+        items: HashMap::from([(1, 1), (2, 0)]),
+    });
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count + 1);
+    assert_eq!(&inner_html().await, "1");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_other_entry_changed_should_not_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<ItemsApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.set_state(ItemsState {
+        items: HashMap::from([(1, 0), (2, 1)]),
+    });
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_observed_entry_removed_should_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<ItemsApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.set_state(ItemsState {
+        items: HashMap::from([(2, 0)]),
+    });
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count + 1);
+    assert_eq!(&inner_html().await, "none");
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Clone, Copy, Default)]
+struct PairState {
+    first: i32,
+    second: i32,
+    unobserved: i32,
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct PairAppProps {
+    context: StoreContext<PairState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn PairApp(props: &PairAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<PairState>> context={props.context.clone()}>
+            <div id={"result"}>
+                <PairComponent render_count={props.render_count.clone()} />
+            </div>
+        </ContextProvider<StoreContext<PairState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct PairComponentProps {
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn PairComponent(props: &PairComponentProps) -> Html {
+    let store = use_store::<PairState>();
+
+    let (first, second) = store.map_ref2(|s| &s.first, |s| &s.second);
+    *props.render_count.borrow_mut() += 1;
+    html! { format!("{}{}", *first, *second) }
+}
+
+struct TestContext {
+    props: PairAppProps,
+}
+
+fn setup() -> TestContext {
+    TestContext {
+        props: PairAppProps {
+            context: StoreContext::new(PairState::default()),
+            render_count: Rc::new(RefCell::new(0)),
+        },
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_init_with_initial_values_should_map_initial_values() {
+    //Given
+    let ctx = setup();
+    //When
+    render_with_props::<PairApp>(ctx.props).await;
+    //Then
+    assert_eq!(&inner_html().await, "00");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_any_observed_value_changed_should_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<PairApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.set_state(PairState {
+        second: 1,
+        ..PairState::default()
+    });
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count + 1);
+    assert_eq!(&inner_html().await, "01");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_unobserved_value_changed_should_not_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<PairApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.set_state(PairState {
+        unobserved: 1,
+        ..PairState::default()
+    });
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count);
+}
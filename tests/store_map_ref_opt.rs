@@ -0,0 +1,144 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+struct ListState {
+    items: Vec<i32>,
+}
+
+#[derive(PartialEq, Clone)]
+enum OptType {
+    MapRefOpt,
+    WatchRefOpt,
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct OptAppProps {
+    opt_type: OptType,
+    context: StoreContext<ListState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn OptApp(props: &OptAppProps) -> Html {
+    let render_count = props.render_count.clone();
+    html! {
+        <ContextProvider<StoreContext<ListState>> context={props.context.clone()}>
+        <div id={"result"}>
+        {
+            match &props.opt_type {
+                OptType::MapRefOpt => html! { <MapRefOptComponent {render_count} /> },
+                OptType::WatchRefOpt => html! { <WatchRefOptComponent {render_count} /> },
+            }
+        }
+        </div>
+        </ContextProvider<StoreContext<ListState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct OptComponentProps {
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn MapRefOptComponent(props: &OptComponentProps) -> Html {
+    let store = use_store::<ListState>();
+
+    let first = store.map_ref_opt(|s| s.items.first()).map(|first| *first);
+    *props.render_count.borrow_mut() += 1;
+    match first {
+        Some(first) => html! { { first } },
+        None => html! { "none" },
+    }
+}
+
+#[function_component]
+fn WatchRefOptComponent(props: &OptComponentProps) -> Html {
+    let store = use_store::<ListState>();
+
+    store.watch_ref_opt(|s| s.items.first());
+    *props.render_count.borrow_mut() += 1;
+    match store.state().items.first() {
+        Some(first) => html! { { first } },
+        None => html! { "none" },
+    }
+}
+
+struct TestContext {
+    props: OptAppProps,
+}
+
+fn setup(opt_type: OptType) -> TestContext {
+    TestContext {
+        props: OptAppProps {
+            opt_type,
+            context: StoreContext::new(ListState { items: vec![1] }),
+            render_count: Rc::new(RefCell::new(0)),
+        },
+    }
+}
+
+async fn empty_then_refill(ctx: &TestContext) -> Vec<(String, i32)> {
+    render_with_props::<OptApp>(ctx.props.clone()).await;
+    let mut renders = vec![];
+    for items in [vec![], vec![], vec![2], vec![2, 3]] {
+        ctx.props.context.set_state(ListState { items });
+        renders.push((inner_html().await, *ctx.props.render_count.borrow()));
+    }
+    renders
+}
+
+#[wasm_bindgen_test]
+async fn map_ref_opt_on_init_with_value_should_map_value() {
+    //Given
+    let ctx = setup(OptType::MapRefOpt);
+    //When
+    render_with_props::<OptApp>(ctx.props.clone()).await;
+    //Then
+    assert_eq!(&inner_html().await, "1");
+}
+
+#[wasm_bindgen_test]
+async fn map_ref_opt_on_store_emptied_then_refilled_should_rerender_on_each_change() {
+    //Given
+    let ctx = setup(OptType::MapRefOpt);
+    //When
+    let renders = empty_then_refill(&ctx).await;
+    //Then
+    assert_eq!(
+        renders,
+        vec![
+            ("none".to_string(), 2),
+            ("none".to_string(), 2),
+            ("2".to_string(), 3),
+            ("2".to_string(), 3),
+        ]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn watch_ref_opt_on_store_emptied_then_refilled_should_rerender_on_each_change() {
+    //Given
+    let ctx = setup(OptType::WatchRefOpt);
+    //When
+    let renders = empty_then_refill(&ctx).await;
+    //Then
+    assert_eq!(
+        renders,
+        vec![
+            ("none".to_string(), 2),
+            ("none".to_string(), 2),
+            ("2".to_string(), 3),
+            ("2".to_string(), 3),
+        ]
+    );
+}
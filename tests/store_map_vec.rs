@@ -0,0 +1,111 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Clone)]
+struct Row {
+    id: u32,
+    label: &'static str,
+    clicks: u32,
+}
+
+struct RowsState {
+    rows: Vec<Row>,
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct MapVecAppProps {
+    context: StoreContext<RowsState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn MapVecApp(props: &MapVecAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<RowsState>> context={props.context.clone()}>
+            <div id={"result"}>
+                <MapVecComponent render_count={props.render_count.clone()} />
+            </div>
+        </ContextProvider<StoreContext<RowsState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct MapVecComponentProps {
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn MapVecComponent(props: &MapVecComponentProps) -> Html {
+    let store = use_store::<RowsState>();
+
+    let diff = store.map_vec(|s| &s.rows, |row| row.id, |row| row.label);
+    *props.render_count.borrow_mut() += 1;
+    html! { format!("{:?} {:?} {:?}", diff.inserted, diff.removed, diff.updated) }
+}
+
+fn row(id: u32, label: &'static str) -> Row {
+    Row {
+        id,
+        label,
+        clicks: 0,
+    }
+}
+
+fn setup() -> MapVecAppProps {
+    MapVecAppProps {
+        context: StoreContext::new(RowsState {
+            rows: vec![row(1, "a"), row(2, "b"), row(3, "c")],
+        }),
+        render_count: Rc::new(RefCell::new(0)),
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_render_should_give_empty_diff() {
+    //Given
+    let props = setup();
+    //When
+    render_with_props::<MapVecApp>(props.clone()).await;
+    //Then
+    assert_eq!(&inner_html().await, "[] [] []");
+}
+
+#[wasm_bindgen_test]
+async fn on_rows_changed_should_render_diff_once() {
+    //Given
+    let props = setup();
+    render_with_props::<MapVecApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(RowsState {
+        rows: vec![row(2, "B"), row(3, "c"), row(4, "d")],
+    });
+    //Then
+    assert_eq!(&inner_html().await, "[4] [1] [2]");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_rows_changed_outside_of_value_should_not_rerender() {
+    //Given
+    let props = setup();
+    render_with_props::<MapVecApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    let mut rows = props.context.state().rows.clone();
+    rows[0].clicks += 1;
+    rows.reverse();
+    //When
+    props.context.set_state(RowsState { rows });
+    //Then
+    wait().await;
+    assert_eq!(*props.render_count.borrow(), render_count);
+}
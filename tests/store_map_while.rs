@@ -0,0 +1,93 @@
+mod common;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+struct TestContext {
+    props: StoreAppProps,
+}
+
+fn setup() -> TestContext {
+    TestContext {
+        props: StoreAppProps::new(SubscriptionType::MapWhile),
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_init_with_active_state_should_map_initial_value() {
+    //Given
+    let ctx = setup();
+    //When
+    render_with_props::<StoreApp>(ctx.props).await;
+    //Then
+    assert_eq!(&inner_html().await, "0");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_new_value_while_active_should_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<StoreApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.set_state(StoreState { value: 2 });
+    //Then
+    assert_eq!(&inner_html().await, "1");
+    assert_eq!(*ctx.props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_same_value_while_active_should_not_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<StoreApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.set_state(StoreState { value: 1 });
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_inactive_state_should_rerender_without_value() {
+    //Given
+    let ctx = setup();
+    render_with_props::<StoreApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.set_state(StoreState { value: 10 });
+    //Then
+    assert_eq!(&inner_html().await, "inactive");
+    assert_eq!(*ctx.props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_while_inactive_should_not_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<StoreApp>(ctx.props.clone()).await;
+    ctx.props.context.set_state(StoreState { value: 10 });
+    wait().await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.set_state(StoreState { value: 12 });
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_active_state_again_should_map_new_value() {
+    //Given
+    let ctx = setup();
+    render_with_props::<StoreApp>(ctx.props.clone()).await;
+    ctx.props.context.set_state(StoreState { value: 10 });
+    wait().await;
+    //When
+    ctx.props.context.set_state(StoreState { value: 4 });
+    //Then
+    assert_eq!(&inner_html().await, "2");
+}
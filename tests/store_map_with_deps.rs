@@ -0,0 +1,137 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+struct ListState {
+    items: Vec<i32>,
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct DepsAppProps {
+    by_ref: bool,
+    index: usize,
+    context: StoreContext<ListState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn DepsApp(props: &DepsAppProps) -> Html {
+    let render_count = props.render_count.clone();
+    let index = props.index;
+    html! {
+        <ContextProvider<StoreContext<ListState>> context={props.context.clone()}>
+            <div id={"result"}>
+            if props.by_ref {
+                <MapRefWithDepsComponent {index} {render_count} />
+            } else {
+                <MapWithDepsComponent {index} {render_count} />
+            }
+            </div>
+        </ContextProvider<StoreContext<ListState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct DepsComponentProps {
+    index: usize,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn MapWithDepsComponent(props: &DepsComponentProps) -> Html {
+    let store = use_store::<ListState>();
+
+    let item = store.map_with_deps(props.index, |s, index| s.items[*index]);
+    *props.render_count.borrow_mut() += 1;
+    html! { { item } }
+}
+
+#[function_component]
+fn MapRefWithDepsComponent(props: &DepsComponentProps) -> Html {
+    let store = use_store::<ListState>();
+
+    let item = *store.map_ref_with_deps(props.index, |s, index| &s.items[*index]);
+    *props.render_count.borrow_mut() += 1;
+    html! { { item } }
+}
+
+fn setup(by_ref: bool) -> DepsAppProps {
+    DepsAppProps {
+        by_ref,
+        index: 0,
+        context: StoreContext::new(ListState {
+            items: vec![10, 20, 30],
+        }),
+        render_count: Rc::new(RefCell::new(0)),
+    }
+}
+
+#[wasm_bindgen_test]
+async fn map_with_deps_on_deps_changed_should_map_value_with_new_deps() {
+    //Given
+    let props = setup(false);
+    let mut app = render_with_props::<DepsApp>(props.clone()).await;
+    //When
+    app.update(DepsAppProps { index: 1, ..props });
+    //Then
+    assert_eq!(&inner_html().await, "20");
+}
+
+#[wasm_bindgen_test]
+async fn map_with_deps_on_store_value_changed_after_deps_changed_should_map_value_with_new_deps() {
+    //Given
+    let props = setup(false);
+    let mut app = render_with_props::<DepsApp>(props.clone()).await;
+    app.update(DepsAppProps {
+        index: 2,
+        ..props.clone()
+    });
+    wait().await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(ListState {
+        items: vec![10, 20, 31],
+    });
+    //Then
+    assert_eq!(&inner_html().await, "31");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn map_with_deps_on_store_value_changed_at_previous_deps_should_not_rerender() {
+    //Given
+    let props = setup(false);
+    let mut app = render_with_props::<DepsApp>(props.clone()).await;
+    app.update(DepsAppProps {
+        index: 1,
+        ..props.clone()
+    });
+    wait().await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(ListState {
+        items: vec![11, 20, 30],
+    });
+    //Then
+    wait().await;
+    assert_eq!(*props.render_count.borrow(), render_count);
+}
+
+#[wasm_bindgen_test]
+async fn map_ref_with_deps_on_deps_changed_should_map_value_with_new_deps() {
+    //Given
+    let props = setup(true);
+    let mut app = render_with_props::<DepsApp>(props.clone()).await;
+    //When
+    app.update(DepsAppProps { index: 1, ..props });
+    //Then
+    assert_eq!(&inner_html().await, "20");
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+struct Sidebar;
+struct Main;
+
+#[derive(Properties, PartialEq, Clone)]
+struct MarkedAppProps {
+    sidebar: StoreContext<StoreState, Sidebar>,
+    main: StoreContext<StoreState, Main>,
+}
+
+impl MarkedAppProps {
+    fn new() -> Self {
+        Self {
+            sidebar: StoreContext::new(StoreState { value: 1 }).marked(),
+            main: StoreContext::new(StoreState { value: 2 }).marked(),
+        }
+    }
+}
+
+#[function_component]
+fn MarkedApp(props: &MarkedAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<StoreState, Sidebar>> context={props.sidebar.clone()}>
+        <ContextProvider<StoreContext<StoreState, Main>> context={props.main.clone()}>
+        <div id={"result"}>
+            <MarkedComponent<Sidebar> />
+            {"|"}
+            <MarkedComponent<Main> />
+        </div>
+        </ContextProvider<StoreContext<StoreState, Main>>>
+        </ContextProvider<StoreContext<StoreState, Sidebar>>>
+    }
+}
+
+#[function_component]
+fn MarkedComponent<K: 'static>() -> Html {
+    let store = use_marked_store::<StoreState, K>();
+
+    let value = store.map(|s| s.value);
+    html! { { value } }
+}
+
+#[wasm_bindgen_test]
+async fn on_init_with_marked_stores_should_map_each_store_value() {
+    //Given
+    let props = MarkedAppProps::new();
+    //When
+    render_with_props::<MarkedApp>(props).await;
+    //Then
+    assert_eq!(&inner_html().await, "1|2");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_marked_stores_should_only_update_changed_store() {
+    //Given
+    let props = MarkedAppProps::new();
+    render_with_props::<MarkedApp>(props.clone()).await;
+    //When
+    props.main.set_state(StoreState { value: 3 });
+    //Then
+    assert_eq!(&inner_html().await, "1|3");
+}
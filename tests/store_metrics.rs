@@ -0,0 +1,36 @@
+#![cfg(feature = "metrics")]
+mod common;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_mapped_value_changed_should_request_render() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::Map);
+    render_with_props::<StoreApp>(props.clone()).await;
+    props.context.reset_metrics();
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    let metrics = props.context.metrics();
+    assert_eq!(metrics.notifications, 1);
+    assert_eq!(metrics.comparator_runs, 1);
+    assert_eq!(metrics.renders_requested, 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_mapped_value_unchanged_should_not_request_render() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::MapWith);
+    render_with_props::<StoreApp>(props.clone()).await;
+    props.context.reset_metrics();
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    let metrics = props.context.metrics();
+    assert_eq!(metrics.comparator_runs, 1);
+    assert_eq!(metrics.renders_requested, 0);
+}
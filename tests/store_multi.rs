@@ -0,0 +1,101 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+struct LabelState {
+    label: String,
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct MultiAppProps {
+    context: StoreContext<StoreState>,
+    label_context: StoreContext<LabelState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn MultiApp(props: &MultiAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<StoreState>> context={props.context.clone()}>
+            <ContextProvider<StoreContext<LabelState>> context={props.label_context.clone()}>
+                <div id={"result"}>
+                    <MultiComponent render_count={props.render_count.clone()} />
+                </div>
+            </ContextProvider<StoreContext<LabelState>>>
+        </ContextProvider<StoreContext<StoreState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct MultiComponentProps {
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn MultiComponent(props: &MultiComponentProps) -> Html {
+    let (store, label_store) = use_stores::<(StoreState, LabelState)>();
+
+    let value = store.map(|s| s.value);
+    let label = label_store.map_ref(|s| &s.label);
+    *props.render_count.borrow_mut() += 1;
+    html! { format!("{} {}", label, value) }
+}
+
+fn setup() -> MultiAppProps {
+    MultiAppProps {
+        context: StoreContext::new(StoreState { value: 0 }),
+        label_context: StoreContext::new(LabelState {
+            label: "value".to_string(),
+        }),
+        render_count: Rc::new(RefCell::new(0)),
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_first_store_value_changed_should_render_new_value_once() {
+    //Given
+    let props = setup();
+    render_with_props::<MultiApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    assert_eq!(&inner_html().await, "value 1");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_second_store_value_changed_should_render_new_value_once() {
+    //Given
+    let props = setup();
+    render_with_props::<MultiApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.label_context.set_state(LabelState {
+        label: "total".to_string(),
+    });
+    //Then
+    assert_eq!(&inner_html().await, "total 0");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_unchanged_should_not_rerender() {
+    //Given
+    let props = setup();
+    render_with_props::<MultiApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 0 });
+    //Then
+    wait().await;
+    assert_eq!(*props.render_count.borrow(), render_count);
+}
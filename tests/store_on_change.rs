@@ -0,0 +1,113 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Clone)]
+struct OnChangeAppProps {
+    context: StoreContext<StoreState>,
+    changed_values: Rc<RefCell<Vec<i32>>>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn OnChangeApp(props: &OnChangeAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<StoreState>> context={props.context.clone()}>
+            <div id={"result"}>
+                <OnChangeComponent
+                    changed_values={props.changed_values.clone()}
+                    render_count={props.render_count.clone()}
+                />
+            </div>
+        </ContextProvider<StoreContext<StoreState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct OnChangeComponentProps {
+    changed_values: Rc<RefCell<Vec<i32>>>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn OnChangeComponent(props: &OnChangeComponentProps) -> Html {
+    let store = use_store::<StoreState>();
+
+    store.on_change(|s| s.value / 2, {
+        let changed_values = props.changed_values.clone();
+        move |value| changed_values.borrow_mut().push(*value)
+    });
+    *props.render_count.borrow_mut() += 1;
+    html! {}
+}
+
+struct TestContext {
+    props: OnChangeAppProps,
+}
+
+fn setup() -> TestContext {
+    TestContext {
+        props: OnChangeAppProps {
+            context: StoreContext::new(StoreState { value: 0 }),
+            changed_values: Rc::new(RefCell::new(vec![])),
+            render_count: Rc::new(RefCell::new(0)),
+        },
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_selected_value_changed_should_run_effect() {
+    //Given
+    let ctx = setup();
+    render_with_props::<OnChangeApp>(ctx.props.clone()).await;
+    //When
+    ctx.props.context.set_state(StoreState { value: 2 });
+    //Then
+    assert_eq!(*ctx.props.changed_values.borrow(), &[1]);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_selected_value_unchanged_should_not_run_effect() {
+    //Given
+    let ctx = setup();
+    render_with_props::<OnChangeApp>(ctx.props.clone()).await;
+    //When
+    ctx.props.context.set_state(StoreState { value: 1 });
+    //Then
+    assert!(ctx.props.changed_values.borrow().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_selected_value_changed_should_not_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<OnChangeApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.set_state(StoreState { value: 2 });
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_after_unmount_should_not_run_effect() {
+    //Given
+    let ctx = setup();
+    let app = render_with_props::<OnChangeApp>(ctx.props.clone()).await;
+    app.destroy();
+    wait().await;
+    //When
+    ctx.props.context.set_state(StoreState { value: 2 });
+    //Then
+    assert!(ctx.props.changed_values.borrow().is_empty());
+    assert_eq!(ctx.props.context.subscriber_count(), 0);
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use common::*;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Clone)]
+struct StoreOptAppProps {
+    context: Option<StoreContext<StoreState>>,
+}
+
+#[function_component]
+fn StoreOptApp(props: &StoreOptAppProps) -> Html {
+    match &props.context {
+        Some(context) => html! {
+            <ContextProvider<StoreContext<StoreState>> context={context.clone()}>
+                <div id={"result"}><StoreOptComponent /></div>
+            </ContextProvider<StoreContext<StoreState>>>
+        },
+        None => html! {
+            <div id={"result"}><StoreOptComponent /></div>
+        },
+    }
+}
+
+#[function_component]
+fn StoreOptComponent() -> Html {
+    match use_store_opt::<StoreState>() {
+        Some(store) => html! { { store.map_ref(|s| &s.value) } },
+        None => html! { "none" },
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_init_without_store_context_should_render_fallback() {
+    //Given
+    let props = StoreOptAppProps { context: None };
+    //When
+    render_with_props::<StoreOptApp>(props).await;
+    //Then
+    assert_eq!(&inner_html().await, "none");
+}
+
+#[wasm_bindgen_test]
+async fn on_init_with_store_context_should_map_initial_value() {
+    //Given
+    let props = StoreOptAppProps {
+        context: Some(StoreContext::new(StoreState { value: 0 })),
+    };
+    //When
+    render_with_props::<StoreOptApp>(props).await;
+    //Then
+    assert_eq!(&inner_html().await, "0");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_store_context_should_map_new_value() {
+    //Given
+    let context = StoreContext::new(StoreState { value: 0 });
+    let props = StoreOptAppProps {
+        context: Some(context.clone()),
+    };
+    render_with_props::<StoreOptApp>(props).await;
+    //When
+    context.set_state(StoreState { value: 1 });
+    //Then
+    assert_eq!(&inner_html().await, "1");
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct StoreOptLateAppProps {
+    setter: Rc<RefCell<Option<UseStateSetter<Option<StoreContext<StoreState>>>>>>,
+}
+
+#[function_component]
+fn StoreOptLateApp(props: &StoreOptLateAppProps) -> Html {
+    let context = use_state(|| None);
+    *props.setter.borrow_mut() = Some(context.setter());
+    html! { <StoreOptApp context={(*context).clone()} /> }
+}
+
+#[wasm_bindgen_test]
+async fn on_store_context_added_after_mount_should_map_new_store_value() {
+    //Given
+    let props = StoreOptLateAppProps {
+        setter: Rc::new(RefCell::new(None)),
+    };
+    let setter = props.setter.clone();
+    render_with_props::<StoreOptLateApp>(props).await;
+    assert_eq!(&inner_html().await, "none");
+    let context = StoreContext::new(StoreState { value: 1 });
+    //When
+    setter.borrow().as_ref().unwrap().set(Some(context.clone()));
+    wait().await;
+    context.set_state(StoreState { value: 2 });
+    //Then
+    assert_eq!(&inner_html().await, "2");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_context_replaced_should_only_map_new_store_value() {
+    //Given
+    let props = StoreOptLateAppProps {
+        setter: Rc::new(RefCell::new(None)),
+    };
+    let setter = props.setter.clone();
+    let first = StoreContext::new(StoreState { value: 1 });
+    let second = StoreContext::new(StoreState { value: 10 });
+    render_with_props::<StoreOptLateApp>(props).await;
+    setter.borrow().as_ref().unwrap().set(Some(first.clone()));
+    wait().await;
+    //When
+    setter.borrow().as_ref().unwrap().set(Some(second.clone()));
+    wait().await;
+    first.set_state(StoreState { value: 2 });
+    wait().await;
+    let after_first = inner_html().await;
+    second.set_state(StoreState { value: 11 });
+    //Then
+    assert_eq!(&after_first, "10");
+    assert_eq!(&inner_html().await, "11");
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Clone)]
+struct OrInitAppProps {
+    context: Option<StoreContext<StoreState>>,
+    next: Option<i32>,
+}
+
+#[function_component]
+fn OrInitApp(props: &OrInitAppProps) -> Html {
+    match &props.context {
+        Some(context) => html! {
+            <ContextProvider<StoreContext<StoreState>> context={context.clone()}>
+                <div id={"result"}><OrInitComponent /></div>
+            </ContextProvider<StoreContext<StoreState>>>
+        },
+        None => html! {
+            <div id={"result"}>
+                <OrInitComponent next={props.next} />
+                <OrInitComponent />
+            </div>
+        },
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct OrInitComponentProps {
+    #[prop_or_default]
+    next: Option<i32>,
+}
+
+#[function_component]
+fn OrInitComponent(props: &OrInitComponentProps) -> Html {
+    let store = use_store_or_init(|| StoreState { value: 1 });
+    let value = store.map(|s| s.value);
+    use_effect_with_deps(
+        move |next| {
+            if let Some(next) = *next {
+                store.set_state(StoreState { value: next });
+            }
+        },
+        props.next,
+    );
+
+    html! { <span>{ value }</span> }
+}
+
+struct InitState {
+    label: &'static str,
+}
+
+#[function_component]
+fn InitApp() -> Html {
+    html! {
+        <div id={"result"}><InitComponent /></div>
+    }
+}
+
+#[function_component]
+fn InitComponent() -> Html {
+    let store = use_store_or_init(|| InitState { label: "init" });
+    let label = store.map_ref(|s| &s.label);
+
+    html! { { label } }
+}
+
+#[wasm_bindgen_test]
+async fn on_render_with_registered_store_should_use_registered_store() {
+    //Given
+    let context = StoreContext::new(StoreState { value: 0 });
+    //When
+    render_with_props::<OrInitApp>(OrInitAppProps {
+        context: Some(context.clone()),
+        next: None,
+    })
+    .await;
+    context.set_state(StoreState { value: 3 });
+    //Then
+    assert_eq!(&inner_html().await, "<span>3</span>");
+}
+
+#[wasm_bindgen_test]
+async fn on_render_without_registered_store_should_init_fallback_store() {
+    //Given
+    //When
+    render_with_props::<InitApp>(()).await;
+    //Then
+    assert_eq!(&inner_html().await, "init");
+}
+
+#[wasm_bindgen_test]
+async fn on_fallback_store_changed_should_change_sibling_store() {
+    //Given
+    let props = OrInitAppProps {
+        context: None,
+        next: Some(2),
+    };
+    //When
+    render_with_props::<OrInitApp>(props).await;
+    //Then
+    assert_eq!(&inner_html().await, "<span>2</span><span>2</span>");
+}
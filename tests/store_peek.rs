@@ -0,0 +1,29 @@
+mod common;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn on_init_with_initial_value_should_peek_value() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::Peek);
+    //When
+    render_with_props::<StoreApp>(props).await;
+    //Then
+    assert_eq!(&inner_html().await, "0");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_peeked_value_should_not_rerender() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::Peek);
+    render_with_props::<StoreApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    assert_eq!(&inner_html().await, "0");
+    assert_eq!(*props.render_count.borrow(), render_count);
+}
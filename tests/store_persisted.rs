@@ -0,0 +1,67 @@
+#![cfg(feature = "persist")]
+
+use wasm_bindgen_test::wasm_bindgen_test;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn local_storage() -> web_sys::Storage {
+    gloo_utils::window()
+        .local_storage()
+        .unwrap()
+        .expect("localStorage to be available")
+}
+
+#[wasm_bindgen_test]
+fn new_persisted_with_stored_state_should_hydrate_stored_state() {
+    //Given
+    local_storage().set_item("hydrate", "1").unwrap();
+    //When
+    let store = StoreContext::new_persisted("hydrate", 0);
+    //Then
+    assert_eq!(*store.state(), 1);
+}
+
+#[wasm_bindgen_test]
+fn new_persisted_with_invalid_stored_state_should_fall_back_to_default() {
+    //Given
+    local_storage().set_item("invalid", "{").unwrap();
+    //When
+    let store = StoreContext::new_persisted("invalid", 0);
+    //Then
+    assert_eq!(*store.state(), 0);
+}
+
+#[wasm_bindgen_test]
+fn set_state_with_persisted_store_should_write_state() {
+    //Given
+    let store = StoreContext::new_persisted("write", 0);
+    //When
+    store.set_state(2);
+    //Then
+    assert_eq!(
+        local_storage().get_item("write").unwrap(),
+        Some("2".to_string())
+    );
+}
+
+#[wasm_bindgen_test]
+fn clear_persisted_with_stored_state_should_remove_state() {
+    //Given
+    let store = StoreContext::new_persisted("clear", 0);
+    store.set_state(3);
+    //When
+    StoreContext::<i32>::clear_persisted("clear");
+    //Then
+    assert_eq!(local_storage().get_item("clear").unwrap(), None);
+}
+
+#[wasm_bindgen_test]
+fn persistent_with_stored_state_should_hydrate_stored_state() {
+    //Given
+    local_storage().set_item("persistent", "4").unwrap();
+    //When
+    let store = StoreContext::persistent("persistent", 0);
+    //Then
+    assert_eq!(*store.state(), 4);
+}
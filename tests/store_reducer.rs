@@ -0,0 +1,71 @@
+mod common;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+enum Action {
+    Increment,
+    Reset,
+}
+
+struct TestContext {
+    props: StoreAppProps,
+    store: ReducerStoreContext<StoreState, Action>,
+}
+
+fn setup() -> TestContext {
+    let store = StoreContext::new_reducer(StoreState { value: 0 }, |state, action| match action {
+        Action::Increment => StoreState {
+            value: state.value + 1,
+        },
+        Action::Reset => StoreState { value: 0 },
+    });
+    TestContext {
+        props: StoreAppProps {
+            context: store.context(),
+            ..StoreAppProps::new(SubscriptionType::MapRef)
+        },
+        store,
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_dispatch_with_changing_action_should_map_new_value() {
+    //Given
+    let ctx = setup();
+    render_with_props::<StoreApp>(ctx.props.clone()).await;
+    //When
+    ctx.store.dispatch(Action::Increment);
+    ctx.store.dispatch(Action::Increment);
+    //Then
+    assert_eq!(&inner_html().await, "2");
+}
+
+#[wasm_bindgen_test]
+async fn on_dispatch_with_changing_action_should_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<StoreApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.store.dispatch(Action::Increment);
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_dispatch_with_trivial_action_should_not_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<StoreApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.store.dispatch(Action::Reset);
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count);
+}
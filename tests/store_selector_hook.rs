@@ -0,0 +1,124 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Clone)]
+struct PairState {
+    first: i32,
+    second: i32,
+    unobserved: i32,
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct SelectorAppProps {
+    context: StoreContext<PairState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn SelectorApp(props: &SelectorAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<PairState>> context={props.context.clone()}>
+            <div id={"result"}>
+                <SelectorComponent render_count={props.render_count.clone()} />
+            </div>
+        </ContextProvider<StoreContext<PairState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct SelectorComponentProps {
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn SelectorComponent(props: &SelectorComponentProps) -> Html {
+    let first = use_selector(|s: &PairState| s.first);
+    let second = use_selector_eq(
+        |s: &PairState| s.second,
+        |prev, next| prev / 10 == next / 10,
+    );
+    *props.render_count.borrow_mut() += 1;
+    html! { format!("{} {}", first, second) }
+}
+
+struct TestContext {
+    props: SelectorAppProps,
+}
+
+fn setup() -> TestContext {
+    TestContext {
+        props: SelectorAppProps {
+            context: StoreContext::new(PairState {
+                first: 0,
+                second: 0,
+                unobserved: 0,
+            }),
+            render_count: Rc::new(RefCell::new(0)),
+        },
+    }
+}
+
+fn update(ctx: &TestContext, f: impl FnOnce(&mut PairState)) {
+    let mut state = (*ctx.props.context.state()).clone();
+    f(&mut state);
+    ctx.props.context.set_state(state);
+}
+
+#[wasm_bindgen_test]
+async fn on_init_with_two_selectors_should_select_both_values() {
+    //Given
+    let ctx = setup();
+    //When
+    render_with_props::<SelectorApp>(ctx.props.clone()).await;
+    //Then
+    assert_eq!(&inner_html().await, "0 0");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_each_selected_value_changed_should_select_new_values() {
+    //Given
+    let ctx = setup();
+    render_with_props::<SelectorApp>(ctx.props.clone()).await;
+    //When
+    update(&ctx, |s| {
+        s.first = 1;
+        s.second = 10;
+    });
+    //Then
+    assert_eq!(&inner_html().await, "1 10");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_equal_value_according_to_comparator_should_not_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<SelectorApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    update(&ctx, |s| s.second = 5);
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_unobserved_value_changed_should_not_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<SelectorApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    update(&ctx, |s| s.unobserved = 1);
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count);
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "sync")]
+
+use gloo::timers::future::sleep;
+use std::time::Duration;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+async fn wait_for_messages() {
+    sleep(Duration::from_millis(50)).await;
+}
+
+#[wasm_bindgen_test]
+async fn set_state_with_synced_stores_should_sync_state() {
+    //Given
+    let store = StoreContext::synced("set_state", 0);
+    let other = StoreContext::synced("set_state", 0);
+    wait_for_messages().await;
+    //When
+    store.set_state(1);
+    //Then
+    wait_for_messages().await;
+    assert_eq!(*other.state(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn set_state_with_synced_stores_should_not_echo_state() {
+    //Given
+    let store = StoreContext::synced("echo", 0);
+    let _other = StoreContext::synced("echo", 0);
+    wait_for_messages().await;
+    let notifications = store.stats().notifications;
+    //When
+    store.set_state(1);
+    //Then
+    wait_for_messages().await;
+    assert_eq!(store.stats().notifications, notifications + 1);
+}
+
+#[wasm_bindgen_test]
+async fn synced_with_existing_synced_store_should_request_state() {
+    //Given
+    let store = StoreContext::synced("request", 0);
+    store.set_state(2);
+    //When
+    let other = StoreContext::synced("request", 0);
+    //Then
+    wait_for_messages().await;
+    assert_eq!(*other.state(), 2);
+}
+
+#[wasm_bindgen_test]
+async fn set_state_with_new_synced_stores_should_sync_state() {
+    //Given
+    let store = StoreContext::new_synced("new_synced", 0);
+    let other = StoreContext::new_synced("new_synced", 0);
+    wait_for_messages().await;
+    //When
+    store.set_state(1);
+    //Then
+    wait_for_messages().await;
+    assert_eq!(*other.state(), 1);
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "timers")]
+
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use common::*;
+use gloo::timers::future::sleep;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+const INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Properties, PartialEq, Clone)]
+struct ThrottledAppProps {
+    mode: ThrottleMode,
+    context: StoreContext<StoreState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn ThrottledApp(props: &ThrottledAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<StoreState>> context={props.context.clone()}>
+            <div id={"result"}>
+                <ThrottledComponent mode={props.mode} render_count={props.render_count.clone()} />
+            </div>
+        </ContextProvider<StoreContext<StoreState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ThrottledComponentProps {
+    mode: ThrottleMode,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn ThrottledComponent(props: &ThrottledComponentProps) -> Html {
+    let store = use_store::<StoreState>();
+
+    let value = store.map_throttled(INTERVAL, |s| s.value, props.mode);
+    *props.render_count.borrow_mut() += 1;
+    html! { { value } }
+}
+
+fn setup(mode: ThrottleMode) -> ThrottledAppProps {
+    ThrottledAppProps {
+        mode,
+        context: StoreContext::new(StoreState { value: 0 }),
+        render_count: Rc::new(RefCell::new(0)),
+    }
+}
+
+async fn write_five_times(context: &StoreContext<StoreState>) {
+    for value in 1..=5 {
+        context.set_state(StoreState { value });
+        wait().await;
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_five_times_with_leading_should_render_first_change_once() {
+    //Given
+    let props = setup(ThrottleMode::Leading);
+    render_with_props::<ThrottledApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    write_five_times(&props.context).await;
+    sleep(INTERVAL * 2).await;
+    //Then
+    assert_eq!(&inner_html().await, "1");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_five_times_with_trailing_should_render_last_change_once() {
+    //Given
+    let props = setup(ThrottleMode::Trailing);
+    render_with_props::<ThrottledApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    write_five_times(&props.context).await;
+    sleep(INTERVAL * 2).await;
+    //Then
+    assert_eq!(&inner_html().await, "5");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_five_times_with_both_should_render_first_and_last_changes() {
+    //Given
+    let props = setup(ThrottleMode::Both);
+    render_with_props::<ThrottledApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    write_five_times(&props.context).await;
+    sleep(INTERVAL * 2).await;
+    //Then
+    assert_eq!(&inner_html().await, "5");
+    assert_eq!(*props.render_count.borrow(), render_count + 2);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_then_unmount_should_cancel_timer() {
+    //Given
+    let props = setup(ThrottleMode::Trailing);
+    let app = render_with_props::<ThrottledApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    app.destroy();
+    sleep(INTERVAL * 2).await;
+    //Then
+    assert_eq!(*props.render_count.borrow(), render_count);
+}
+
+#[wasm_bindgen_test]
+async fn on_dropped_value_repeated_after_interval_with_leading_should_render_it() {
+    //Given
+    let props = setup(ThrottleMode::Leading);
+    render_with_props::<ThrottledApp>(props.clone()).await;
+    props.context.set_state(StoreState { value: 1 });
+    wait().await;
+    props.context.set_state(StoreState { value: 2 });
+    sleep(INTERVAL * 2).await;
+    //When
+    props.context.set_state(StoreState { value: 2 });
+    //Then
+    assert_eq!(&inner_html().await, "2");
+}
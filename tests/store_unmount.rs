@@ -0,0 +1,44 @@
+mod common;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn on_render_with_mapped_value_should_subscribe_once() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::Map);
+    //When
+    render_with_props::<StoreApp>(props.clone()).await;
+    props.context.set_state(StoreState { value: 1 });
+    wait().await;
+    //Then
+    assert_eq!(props.context.subscription_count(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_unmount_with_mapped_value_should_drop_subscription_on_next_change() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::Map);
+    let app = render_with_props::<StoreApp>(props.clone()).await;
+    //When
+    app.destroy();
+    wait().await;
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    assert_eq!(props.context.subscription_count(), 0);
+}
+
+#[wasm_bindgen_test]
+async fn on_unmount_with_watched_value_should_drop_subscription_on_next_change() {
+    //Given
+    let props = StoreAppProps::new(SubscriptionType::Watch);
+    let app = render_with_props::<StoreApp>(props.clone()).await;
+    //When
+    app.destroy();
+    wait().await;
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    assert_eq!(props.context.subscription_count(), 0);
+}
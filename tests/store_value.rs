@@ -0,0 +1,104 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Clone)]
+struct ValueAppProps {
+    with_map_ref: bool,
+    context: StoreContext<StoreState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn ValueApp(props: &ValueAppProps) -> Html {
+    let render_count = props.render_count.clone();
+    html! {
+        <ContextProvider<StoreContext<StoreState>> context={props.context.clone()}>
+            <div id={"result"}>
+            if props.with_map_ref {
+                <ValueMapRefComponent {render_count} />
+            } else {
+                <ValueComponent {render_count} />
+            }
+            </div>
+        </ContextProvider<StoreContext<StoreState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ValueComponentProps {
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn ValueComponent(props: &ValueComponentProps) -> Html {
+    let state = use_store_value::<StoreState>();
+
+    *props.render_count.borrow_mut() += 1;
+    html! { { state.value } }
+}
+
+#[function_component]
+fn ValueMapRefComponent(props: &ValueComponentProps) -> Html {
+    let state = use_store_value::<StoreState>();
+    let store = use_store::<StoreState>();
+
+    let value = store.map_ref(|s| &s.value);
+    *props.render_count.borrow_mut() += 1;
+    html! { format!("{} {}", state.value, *value) }
+}
+
+fn setup(with_map_ref: bool) -> ValueAppProps {
+    ValueAppProps {
+        with_map_ref,
+        context: StoreContext::new(StoreState { value: 0 }),
+        render_count: Rc::new(RefCell::new(0)),
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_should_render_new_state_once() {
+    //Given
+    let props = setup(false);
+    render_with_props::<ValueApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    assert_eq!(&inner_html().await, "1");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_state_set_with_equal_state_should_rerender() {
+    //Given
+    let props = setup(false);
+    render_with_props::<ValueApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 0 });
+    //Then
+    wait().await;
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_map_ref_should_render_once() {
+    //Given
+    let props = setup(true);
+    render_with_props::<ValueApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    //Then
+    assert_eq!(&inner_html().await, "1 1");
+    assert_eq!(*props.render_count.borrow(), render_count + 1);
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq, Clone)]
+struct WatchChangesAppProps {
+    silent: bool,
+    context: StoreContext<StoreState>,
+    changes: Rc<RefCell<Vec<(i32, i32)>>>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn WatchChangesApp(props: &WatchChangesAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<StoreState>> context={props.context.clone()}>
+            <div id={"result"}>
+                <WatchChangesComponent
+                    silent={props.silent}
+                    changes={props.changes.clone()}
+                    render_count={props.render_count.clone()}
+                />
+            </div>
+        </ContextProvider<StoreContext<StoreState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct WatchChangesComponentProps {
+    silent: bool,
+    changes: Rc<RefCell<Vec<(i32, i32)>>>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn WatchChangesComponent(props: &WatchChangesComponentProps) -> Html {
+    let store = use_store::<StoreState>();
+
+    let on_change = {
+        let changes = props.changes.clone();
+        move |prev: &i32, next: &i32| changes.borrow_mut().push((*prev, *next))
+    };
+    if props.silent {
+        store.watch_changes_silent(|s| s.value, on_change);
+    } else {
+        store.watch_changes(|s| s.value, on_change);
+    }
+    *props.render_count.borrow_mut() += 1;
+    html! { { store.state().value } }
+}
+
+fn setup(silent: bool) -> WatchChangesAppProps {
+    WatchChangesAppProps {
+        silent,
+        context: StoreContext::new(StoreState { value: 0 }),
+        changes: Rc::new(RefCell::new(vec![])),
+        render_count: Rc::new(RefCell::new(0)),
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_render_should_not_invoke_on_change() {
+    //Given
+    let props = setup(false);
+    //When
+    render_with_props::<WatchChangesApp>(props.clone()).await;
+    //Then
+    assert!(props.changes.borrow().is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_twice_should_invoke_on_change_with_each_change() {
+    //Given
+    let props = setup(false);
+    render_with_props::<WatchChangesApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    wait().await;
+    props.context.set_state(StoreState { value: 3 });
+    //Then
+    assert_eq!(&inner_html().await, "3");
+    assert_eq!(*props.changes.borrow(), &[(0, 1), (1, 3)]);
+    assert_eq!(*props.render_count.borrow(), render_count + 2);
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_twice_with_silent_should_invoke_on_change_without_render() {
+    //Given
+    let props = setup(true);
+    render_with_props::<WatchChangesApp>(props.clone()).await;
+    let render_count = *props.render_count.borrow();
+    //When
+    props.context.set_state(StoreState { value: 1 });
+    props.context.set_state(StoreState { value: 3 });
+    //Then
+    wait().await;
+    assert_eq!(*props.changes.borrow(), &[(0, 1), (1, 3)]);
+    assert_eq!(*props.render_count.borrow(), render_count);
+}
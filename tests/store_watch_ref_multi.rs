@@ -0,0 +1,97 @@
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::*;
+use wasm_bindgen_test::wasm_bindgen_test;
+use yew::prelude::*;
+use yewv::*;
+
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Clone, Copy, Default)]
+struct TripleState {
+    first: i32,
+    second: i32,
+    third: i32,
+    unobserved: i32,
+}
+
+#[derive(Properties, PartialEq, Clone)]
+struct TripleAppProps {
+    context: StoreContext<TripleState>,
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn TripleApp(props: &TripleAppProps) -> Html {
+    html! {
+        <ContextProvider<StoreContext<TripleState>> context={props.context.clone()}>
+            <div id={"result"}>
+                <TripleComponent render_count={props.render_count.clone()} />
+            </div>
+        </ContextProvider<StoreContext<TripleState>>>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct TripleComponentProps {
+    render_count: Rc<RefCell<i32>>,
+}
+
+#[function_component]
+fn TripleComponent(props: &TripleComponentProps) -> Html {
+    let store = use_store::<TripleState>();
+
+    store.watch_ref3(|s| &s.first, |s| &s.second, |s| &s.third);
+    *props.render_count.borrow_mut() += 1;
+    let state = store.state();
+    html! { format!("{}{}{}", state.first, state.second, state.third) }
+}
+
+struct TestContext {
+    props: TripleAppProps,
+}
+
+fn setup() -> TestContext {
+    TestContext {
+        props: TripleAppProps {
+            context: StoreContext::new(TripleState::default()),
+            render_count: Rc::new(RefCell::new(0)),
+        },
+    }
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_any_observed_value_changed_should_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<TripleApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.set_state(TripleState {
+        third: 1,
+        ..TripleState::default()
+    });
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count + 1);
+    assert_eq!(&inner_html().await, "001");
+}
+
+#[wasm_bindgen_test]
+async fn on_store_value_changed_with_unobserved_value_changed_should_not_rerender() {
+    //Given
+    let ctx = setup();
+    render_with_props::<TripleApp>(ctx.props.clone()).await;
+    let render_count = *ctx.props.render_count.borrow();
+    //When
+    ctx.props.context.set_state(TripleState {
+        unobserved: 1,
+        ..TripleState::default()
+    });
+    //Then
+    wait().await;
+    assert_eq!(*ctx.props.render_count.borrow(), render_count);
+}